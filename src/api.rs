@@ -0,0 +1,175 @@
+use std::sync::atomic::Ordering;
+use std::thread;
+
+use rand::Rng;
+use serde::Serialize;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::{clamp_delay_ms, start_clicker, stop_clicker, AppFlags};
+
+// ------------------- HTTP Control API -------------------
+
+// Every request has to carry the token printed at startup in this header. A web
+// page can't add a custom header to a cross-site request without a CORS
+// preflight, which this server never answers, so browsing can't drive the clicker.
+const TOKEN_HEADER: &str = "X-Autoclicker-Token";
+
+#[derive(Debug, Serialize)]
+struct Status {
+    running: bool,
+    paused: bool,
+    delay_ms: usize,
+    total_clicks: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct ApiError {
+    error: String,
+}
+
+// GET / returns the status; POST /start, /stop and /delay/{ms} change it and
+// return the new status. Everything goes through the same shared state as the
+// GUI and hotkeys, so they all stay in step.
+pub fn serve(host: &str, port: u16, flags: AppFlags) {
+    let mut rng = rand::thread_rng();
+    let token = format!("{:016x}{:016x}", rng.r#gen::<u64>(), rng.r#gen::<u64>());
+    let address = format!("{}:{}", host, port);
+    let server = match Server::http(&address) {
+        Ok(server) => server,
+        Err(e) => {
+            println!("Failed to start HTTP control API on {}: {}", address, e);
+            return;
+        }
+    };
+    println!("HTTP control API listening on http://{}", address);
+    println!("Send the header \"{}: {}\" with every request.", TOKEN_HEADER, token);
+
+    thread::spawn(move || {
+        for request in server.incoming_requests() {
+            let checked = check_headers(
+                header(&request, "Origin"),
+                header(&request, TOKEN_HEADER),
+                &token,
+            );
+            let (code, body) = match checked {
+                Ok(()) => handle(request.method(), request.url(), &flags),
+                Err(rejected) => rejected,
+            };
+            let response = Response::from_string(body).with_status_code(code);
+            let response = match Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+            {
+                Ok(header) => response.with_header(header),
+                Err(()) => response,
+            };
+            if let Err(e) = request.respond(response) {
+                println!("Failed to answer HTTP request: {}", e);
+            }
+        }
+    });
+}
+
+fn header<'a>(request: &'a Request, name: &'static str) -> Option<&'a str> {
+    request
+        .headers()
+        .iter()
+        .find(|header| header.field.equiv(name))
+        .map(|header| header.value.as_str())
+}
+
+// Browsers send an Origin with cross-site requests, so one from a web page is
+// turned away even before the token is looked at.
+fn check_headers(
+    origin: Option<&str>,
+    token: Option<&str>,
+    expected: &str,
+) -> Result<(), (u16, String)> {
+    if let Some(origin) = origin {
+        return Err(error(403, format!("requests from {:?} are not allowed", origin)));
+    }
+    if token != Some(expected) {
+        return Err(error(401, format!("missing or wrong {} header", TOKEN_HEADER)));
+    }
+    Ok(())
+}
+
+fn handle(method: &Method, url: &str, flags: &AppFlags) -> (u16, String) {
+    let path = url.split('?').next().unwrap_or_default().trim_end_matches('/');
+    match (method, path) {
+        (Method::Get, "") => {}
+        (Method::Post, "/start") => start_clicker(flags.is_clicking.clone()),
+        (Method::Post, "/stop") => stop_clicker(flags.is_clicking.clone()),
+        (Method::Post, path) if path.starts_with("/delay/") => {
+            let Ok(delay) = path["/delay/".len()..].parse::<u32>() else {
+                return error(400, format!("invalid delay in {:?}", path));
+            };
+            let delay = clamp_delay_ms(delay);
+            flags.delay_ms.store(delay as usize, Ordering::Relaxed);
+            println!("Delay set to {} ms over HTTP", delay);
+        }
+        (_, path)
+            if matches!(path, "" | "/start" | "/stop") || path.starts_with("/delay/") =>
+        {
+            return error(405, format!("{} not allowed on {:?}", method, path));
+        }
+        _ => return error(404, format!("no such endpoint {:?}", path)),
+    }
+    (200, to_json(&status(flags)))
+}
+
+fn status(flags: &AppFlags) -> Status {
+    Status {
+        running: flags.is_clicking.is_running(),
+        paused: flags.is_clicking.is_paused(),
+        delay_ms: flags.delay_ms.load(Ordering::Relaxed),
+        total_clicks: flags.total_clicks.load(Ordering::Relaxed),
+    }
+}
+
+fn error(code: u16, error: String) -> (u16, String) {
+    (code, to_json(&ApiError { error }))
+}
+
+fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_else(|e| format!("{{\"error\":\"{}\"}}", e))
+}
+// --------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MIN_DELAY_MS;
+
+    #[test]
+    fn requests_need_the_token_and_no_origin() {
+        assert!(check_headers(None, Some("secret"), "secret").is_ok());
+        assert_eq!(check_headers(None, None, "secret").unwrap_err().0, 401);
+        assert_eq!(check_headers(None, Some("guess"), "secret").unwrap_err().0, 401);
+        let from_page = check_headers(Some("https://example.com"), Some("secret"), "secret");
+        assert_eq!(from_page.unwrap_err().0, 403);
+    }
+
+    #[test]
+    fn routes_start_stop_and_delay() {
+        let flags = AppFlags::default();
+        assert_eq!(handle(&Method::Get, "/", &flags).0, 200);
+        assert_eq!(handle(&Method::Post, "/start", &flags).0, 200);
+        assert!(flags.is_clicking.is_running());
+        assert_eq!(handle(&Method::Post, "/stop/", &flags).0, 200);
+        assert!(!flags.is_clicking.is_running());
+
+        assert_eq!(handle(&Method::Post, "/delay/250", &flags).0, 200);
+        assert_eq!(flags.delay_ms.load(Ordering::Relaxed), 250);
+        assert_eq!(handle(&Method::Post, "/delay/0", &flags).0, 200);
+        assert_eq!(flags.delay_ms.load(Ordering::Relaxed), MIN_DELAY_MS as usize);
+        assert_eq!(handle(&Method::Post, "/delay/fast", &flags).0, 400);
+        assert_eq!(handle(&Method::Post, "/delay/99999999999", &flags).0, 400);
+        assert_eq!(handle(&Method::Post, "/delay/-5", &flags).0, 400);
+        assert_eq!(flags.delay_ms.load(Ordering::Relaxed), MIN_DELAY_MS as usize);
+
+        assert_eq!(handle(&Method::Get, "/start", &flags).0, 405);
+        assert_eq!(handle(&Method::Delete, "/delay/100", &flags).0, 405);
+        assert_eq!(handle(&Method::Post, "/", &flags).0, 405);
+        assert_eq!(handle(&Method::Get, "/nope", &flags).0, 404);
+        assert_eq!(handle(&Method::Post, "/delay", &flags).0, 404);
+    }
+}
@@ -0,0 +1,449 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use clap::{value_parser, Arg, ArgAction, Command};
+
+use crate::config::Config;
+use crate::{
+    clamp_delay_ms, AppFlags, ClickMode, ClickType, Hotkey, MouseButtonChoice, MAX_BURST_PAUSE_MS,
+    MAX_BURST_SIZE, MAX_HOLD_MS, MAX_HUMANIZE_STD_DEV_MS, MAX_JITTER_MS, MAX_RANGE_DELAY_MS,
+    MAX_RUN_MINUTES, MAX_START_DELAY_MS,
+};
+
+const DEFAULT_SERVE_HOST: &str = "127.0.0.1";
+
+// ------------------- Command Line -----------------------
+
+// Anything given here overrides the saved config for this run.
+#[derive(Debug, Clone, Default)]
+pub struct CliArgs {
+    pub delay_ms: Option<u32>,
+    pub hotkey: Option<Hotkey>,
+    pub stop_hotkey: Option<Hotkey>,
+    pub click_mode: Option<ClickMode>,
+    pub mouse_button: Option<MouseButtonChoice>,
+    pub max_run_minutes: Option<u32>,
+    // Not part of the saved config; unset leaves the GUI's default.
+    pub jitter_ms: Option<u32>,
+    pub humanize_std_dev_ms: Option<u32>,
+    pub range_ms: Option<(u32, u32)>,
+    pub hold_ms: Option<u32>,
+    pub start_delay_ms: Option<u32>,
+    pub max_clicks: Option<u32>,
+    pub click_type: Option<ClickType>,
+    pub target: Option<(i32, i32)>,
+    pub burst_size: Option<u32>,
+    pub burst_pause_ms: Option<u32>,
+    pub headless: bool,
+    pub serve_port: Option<u16>,
+    pub serve_host: String,
+}
+
+pub fn parse() -> CliArgs {
+    let matches = Command::new("rust_auto_clicker")
+        .about("Auto clicker with a GUI, or headless with --headless")
+        .arg(
+            Arg::new("delay")
+                .long("delay")
+                .value_name("MS")
+                .help("Delay between clicks in milliseconds")
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            Arg::new("hotkey")
+                .long("hotkey")
+                .value_name("KEY")
+                .help("Start/stop hotkey, e.g. F6, PageUp, Numpad0, MouseBack")
+                .value_parser(parse_hotkey),
+        )
+        .arg(
+            Arg::new("stop-hotkey")
+                .long("stop-hotkey")
+                .value_name("KEY")
+                .help("Separate stop hotkey; defaults to the start hotkey")
+                .value_parser(parse_hotkey),
+        )
+        .arg(
+            Arg::new("mode")
+                .long("mode")
+                .value_name("MODE")
+                .help("Toggle to start/stop on each press, Hold to click while held")
+                .value_parser(parse_click_mode),
+        )
+        .arg(
+            Arg::new("button")
+                .long("button")
+                .value_name("BUTTON")
+                .help("Mouse button to click: Left, Right or Middle")
+                .value_parser(parse_mouse_button),
+        )
+        .arg(
+            Arg::new("jitter")
+                .long("jitter")
+                .value_name("MS")
+                .help("Random ± variation added to each delay")
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            Arg::new("humanize")
+                .long("humanize")
+                .value_name("STD_DEV_MS")
+                .help("Vary delays on a bell curve with this standard deviation instead of jitter")
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            Arg::new("range")
+                .long("range")
+                .value_name("MIN-MAX")
+                .help("Pick each delay at random between MIN and MAX ms, e.g. 200-600")
+                .value_parser(parse_range),
+        )
+        .arg(
+            Arg::new("hold")
+                .long("hold")
+                .value_name("MS")
+                .help("Keep the button down this long on each click; 0 taps")
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            Arg::new("start-delay")
+                .long("start-delay")
+                .value_name("MS")
+                .help("Wait this long after starting before the first click")
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            Arg::new("max-clicks")
+                .long("max-clicks")
+                .value_name("COUNT")
+                .help("Stop after this many clicks; 0 is unlimited")
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            Arg::new("max-run")
+                .long("max-run")
+                .value_name("MINUTES")
+                .help("Stop after running this long; 0 is unlimited")
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            Arg::new("click-type")
+                .long("click-type")
+                .value_name("TYPE")
+                .help("Single, Double or Triple")
+                .value_parser(parse_click_type),
+        )
+        .arg(
+            Arg::new("target")
+                .long("target")
+                .value_name("X,Y")
+                .help("Click at this screen position instead of the cursor")
+                .value_parser(parse_position),
+        )
+        .arg(
+            Arg::new("burst")
+                .long("burst")
+                .value_name("SIZE")
+                .help("Click in bursts of this many, pausing between them")
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            Arg::new("burst-pause")
+                .long("burst-pause")
+                .value_name("MS")
+                .help("Pause between bursts")
+                .value_parser(value_parser!(u32))
+                .requires("burst"),
+        )
+        .arg(
+            Arg::new("headless")
+                .long("headless")
+                .visible_alias("no-gui")
+                .help("Run without the window, controlled by the hotkey only")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("serve")
+                .long("serve")
+                .value_name("PORT")
+                .help("Serve the HTTP control API (GET /, POST /start, /stop, /delay/{ms}); \
+                       requests need the token printed at startup")
+                .value_parser(value_parser!(u16)),
+        )
+        .arg(
+            Arg::new("serve-host")
+                .long("serve-host")
+                .value_name("HOST")
+                .help("Address for --serve to bind; anything but localhost exposes the API")
+                .default_value(DEFAULT_SERVE_HOST)
+                .requires("serve"),
+        )
+        .get_matches();
+
+    CliArgs {
+        delay_ms: matches.get_one::<u32>("delay").copied(),
+        hotkey: matches.get_one::<Hotkey>("hotkey").copied(),
+        stop_hotkey: matches.get_one::<Hotkey>("stop-hotkey").copied(),
+        click_mode: matches.get_one::<ClickMode>("mode").copied(),
+        mouse_button: matches.get_one::<MouseButtonChoice>("button").copied(),
+        max_run_minutes: matches.get_one::<u32>("max-run").copied(),
+        jitter_ms: matches.get_one::<u32>("jitter").copied(),
+        humanize_std_dev_ms: matches.get_one::<u32>("humanize").copied(),
+        range_ms: matches.get_one::<(u32, u32)>("range").copied(),
+        hold_ms: matches.get_one::<u32>("hold").copied(),
+        start_delay_ms: matches.get_one::<u32>("start-delay").copied(),
+        max_clicks: matches.get_one::<u32>("max-clicks").copied(),
+        click_type: matches.get_one::<ClickType>("click-type").copied(),
+        target: matches.get_one::<(i32, i32)>("target").copied(),
+        burst_size: matches.get_one::<u32>("burst").copied(),
+        burst_pause_ms: matches.get_one::<u32>("burst-pause").copied(),
+        headless: matches.get_flag("headless"),
+        serve_port: matches.get_one::<u16>("serve").copied(),
+        serve_host: matches
+            .get_one::<String>("serve-host")
+            .cloned()
+            .unwrap_or_else(|| String::from(DEFAULT_SERVE_HOST)),
+    }
+}
+
+impl CliArgs {
+    // The settings outside the saved config go straight into the shared state,
+    // limited to what the GUI's controls allow.
+    pub fn apply_runtime(&self, flags: &AppFlags) {
+        let store = |atomic: &AtomicUsize, value: u32| {
+            atomic.store(value as usize, Ordering::Relaxed);
+        };
+        if let Some(jitter_ms) = self.jitter_ms {
+            store(&flags.jitter_ms, jitter_ms.min(MAX_JITTER_MS));
+        }
+        if let Some(std_dev_ms) = self.humanize_std_dev_ms {
+            flags.humanize_enabled.store(true, Ordering::Relaxed);
+            store(&flags.humanize_std_dev_ms, std_dev_ms.min(MAX_HUMANIZE_STD_DEV_MS));
+        }
+        if let Some((min, max)) = self.range_ms {
+            flags.range_enabled.store(true, Ordering::Relaxed);
+            store(&flags.range_min_ms, clamp_delay_ms(min).min(MAX_RANGE_DELAY_MS));
+            store(&flags.range_max_ms, clamp_delay_ms(max).min(MAX_RANGE_DELAY_MS));
+        }
+        if let Some(hold_ms) = self.hold_ms {
+            store(&flags.hold_ms, hold_ms.min(MAX_HOLD_MS));
+        }
+        if let Some(start_delay_ms) = self.start_delay_ms {
+            store(&flags.start_delay_ms, start_delay_ms.min(MAX_START_DELAY_MS));
+        }
+        if let Some(max_clicks) = self.max_clicks {
+            store(&flags.max_clicks, max_clicks);
+        }
+        if let Some(click_type) = self.click_type {
+            let mut lock = flags.click_type.lock().unwrap();
+            *lock = click_type;
+        }
+        if let Some(target) = self.target {
+            let mut lock = flags.target_position.lock().unwrap();
+            *lock = Some(target);
+        }
+        if let Some(burst_size) = self.burst_size {
+            flags.burst_enabled.store(true, Ordering::Relaxed);
+            store(&flags.burst_size, burst_size.clamp(1, MAX_BURST_SIZE));
+        }
+        if let Some(burst_pause_ms) = self.burst_pause_ms {
+            store(&flags.burst_pause_ms, burst_pause_ms.min(MAX_BURST_PAUSE_MS));
+        }
+    }
+}
+
+// What the command line changed, as (value for this run, value it replaced), so
+// saving can put the replaced value back and the override never reaches disk.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CliOverrides {
+    delay_ms: Option<(u32, u32)>,
+    hotkey: Option<(Hotkey, Hotkey)>,
+    stop_hotkey: Option<(Hotkey, Hotkey)>,
+    mouse_button: Option<(MouseButtonChoice, MouseButtonChoice)>,
+    click_mode: Option<(ClickMode, ClickMode)>,
+    max_run_ms: Option<(u32, u32)>,
+}
+
+impl CliOverrides {
+    pub fn apply(args: &CliArgs, config: &mut Config) -> Self {
+        let saved = config.clone();
+        if let Some(delay_ms) = args.delay_ms {
+            // Clamped here so it compares equal to what the clicker actually uses.
+            config.delay_ms = clamp_delay_ms(delay_ms);
+        }
+        if let Some(hotkey) = args.hotkey {
+            // A single hotkey from the command line toggles, like the GUI default.
+            config.hotkey = hotkey;
+            config.stop_hotkey = hotkey;
+        }
+        if let Some(stop_hotkey) = args.stop_hotkey {
+            config.stop_hotkey = stop_hotkey;
+        }
+        if let Some(mouse_button) = args.mouse_button {
+            config.mouse_button = mouse_button;
+        }
+        if let Some(click_mode) = args.click_mode {
+            config.click_mode = click_mode;
+        }
+        if let Some(minutes) = args.max_run_minutes {
+            config.max_run_ms = minutes.min(MAX_RUN_MINUTES) * 60_000;
+        }
+
+        let stop_hotkey_set = args.hotkey.is_some() || args.stop_hotkey.is_some();
+        Self {
+            delay_ms: args.delay_ms.map(|_| (config.delay_ms, saved.delay_ms)),
+            hotkey: args.hotkey.map(|_| (config.hotkey, saved.hotkey)),
+            stop_hotkey: stop_hotkey_set.then_some((config.stop_hotkey, saved.stop_hotkey)),
+            mouse_button: args.mouse_button.map(|_| (config.mouse_button, saved.mouse_button)),
+            click_mode: args.click_mode.map(|_| (config.click_mode, saved.click_mode)),
+            max_run_ms: args.max_run_minutes.map(|_| (config.max_run_ms, saved.max_run_ms)),
+        }
+    }
+
+    pub fn restore(&self, config: &mut Config) {
+        restore(&mut config.delay_ms, self.delay_ms);
+        restore(&mut config.hotkey, self.hotkey);
+        restore(&mut config.stop_hotkey, self.stop_hotkey);
+        restore(&mut config.mouse_button, self.mouse_button);
+        restore(&mut config.click_mode, self.click_mode);
+        restore(&mut config.max_run_ms, self.max_run_ms);
+    }
+}
+
+// Only while the setting still holds the override: a value the user picked in
+// the GUI since is theirs and gets saved.
+fn restore<T: PartialEq + Copy>(field: &mut T, overridden: Option<(T, T)>) {
+    if let Some((value, saved)) = overridden
+        && *field == value
+    {
+        *field = saved;
+    }
+}
+
+// Names are the variant names, matched case-insensitively ("f6", "PageUp").
+fn parse_hotkey(value: &str) -> Result<Hotkey, String> {
+    Hotkey::ALL
+        .into_iter()
+        .find(|hotkey| format!("{:?}", hotkey).eq_ignore_ascii_case(value))
+        .ok_or_else(|| format!("unknown hotkey {:?}", value))
+}
+
+fn parse_click_mode(value: &str) -> Result<ClickMode, String> {
+    ClickMode::ALL
+        .into_iter()
+        .find(|mode| format!("{:?}", mode).eq_ignore_ascii_case(value))
+        .ok_or_else(|| format!("unknown mode {:?}", value))
+}
+
+fn parse_mouse_button(value: &str) -> Result<MouseButtonChoice, String> {
+    MouseButtonChoice::ALL
+        .into_iter()
+        .find(|button| format!("{:?}", button).eq_ignore_ascii_case(value))
+        .ok_or_else(|| format!("unknown mouse button {:?}", value))
+}
+
+fn parse_click_type(value: &str) -> Result<ClickType, String> {
+    ClickType::ALL
+        .into_iter()
+        .find(|click_type| format!("{:?}", click_type).eq_ignore_ascii_case(value))
+        .ok_or_else(|| format!("unknown click type {:?}", value))
+}
+
+// "200-600"; a reversed range is an error rather than silently swapped.
+fn parse_range(value: &str) -> Result<(u32, u32), String> {
+    let invalid = || format!("expected MIN-MAX in ms, got {:?}", value);
+    let (min, max) = value.split_once('-').ok_or_else(invalid)?;
+    let min = min.trim().parse::<u32>().map_err(|_| invalid())?;
+    let max = max.trim().parse::<u32>().map_err(|_| invalid())?;
+    if min > max {
+        return Err(format!("range minimum {} is above the maximum {}", min, max));
+    }
+    Ok((min, max))
+}
+
+// "X,Y" in screen pixels; negative values reach monitors left of or above the
+// primary one.
+fn parse_position(value: &str) -> Result<(i32, i32), String> {
+    let invalid = || format!("expected X,Y, got {:?}", value);
+    let (x, y) = value.split_once(',').ok_or_else(invalid)?;
+    let x = x.trim().parse::<i32>().map_err(|_| invalid())?;
+    let y = y.trim().parse::<i32>().map_err(|_| invalid())?;
+    Ok((x, y))
+}
+// --------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_are_put_back_before_saving() {
+        let saved = Config::default();
+        let mut config = saved.clone();
+        let args = CliArgs {
+            delay_ms: Some(42),
+            hotkey: Some(Hotkey::F8),
+            mouse_button: Some(MouseButtonChoice::Right),
+            click_mode: Some(ClickMode::Hold),
+            max_run_minutes: Some(30),
+            ..CliArgs::default()
+        };
+        let overrides = CliOverrides::apply(&args, &mut config);
+        assert_eq!(config.delay_ms, 42);
+        assert_eq!(config.stop_hotkey, Hotkey::F8);
+        assert_eq!(config.max_run_ms, 30 * 60_000);
+
+        overrides.restore(&mut config);
+        assert_eq!(config, saved);
+    }
+
+    #[test]
+    fn settings_changed_after_an_override_are_kept() {
+        let mut config = Config::default();
+        let args = CliArgs { delay_ms: Some(42), hotkey: Some(Hotkey::F8), ..CliArgs::default() };
+        let overrides = CliOverrides::apply(&args, &mut config);
+        config.delay_ms = 300;
+
+        overrides.restore(&mut config);
+        assert_eq!(config.delay_ms, 300);
+        assert_eq!(config.hotkey, Config::default().hotkey);
+    }
+
+    #[test]
+    fn runtime_flags_only_change_what_was_given() {
+        let flags = AppFlags::default();
+        CliArgs::default().apply_runtime(&flags);
+        assert!(!flags.range_enabled.load(Ordering::Relaxed));
+        assert_eq!(*flags.target_position.lock().unwrap(), None);
+
+        let args = CliArgs {
+            jitter_ms: Some(MAX_JITTER_MS + 1),
+            range_ms: Some((0, 600)),
+            hold_ms: Some(50),
+            click_type: Some(ClickType::Double),
+            target: Some((-20, 300)),
+            burst_size: Some(0),
+            ..CliArgs::default()
+        };
+        args.apply_runtime(&flags);
+        assert_eq!(flags.jitter_ms.load(Ordering::Relaxed), MAX_JITTER_MS as usize);
+        assert!(flags.range_enabled.load(Ordering::Relaxed));
+        assert_eq!(flags.range_min_ms.load(Ordering::Relaxed), crate::MIN_DELAY_MS as usize);
+        assert_eq!(flags.range_max_ms.load(Ordering::Relaxed), 600);
+        assert_eq!(flags.hold_ms.load(Ordering::Relaxed), 50);
+        assert_eq!(*flags.click_type.lock().unwrap(), ClickType::Double);
+        assert_eq!(*flags.target_position.lock().unwrap(), Some((-20, 300)));
+        assert!(flags.burst_enabled.load(Ordering::Relaxed));
+        assert_eq!(flags.burst_size.load(Ordering::Relaxed), 1);
+        assert!(!flags.humanize_enabled.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn ranges_and_positions_parse() {
+        assert_eq!(parse_range("200-600"), Ok((200, 600)));
+        assert!(parse_range("600-200").is_err());
+        assert!(parse_range("200").is_err());
+        assert_eq!(parse_position("-1920, 40"), Ok((-1920, 40)));
+        assert!(parse_position("10;20").is_err());
+        assert_eq!(parse_click_type("triple"), Ok(ClickType::Triple));
+    }
+}
@@ -0,0 +1,313 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::i18n::Language;
+use crate::{
+    ClickMode, ClickType, DelayRange, DragMode, Hotkey, KeySequence, Modifier,
+    MouseButtonChoice, SequencePoint, ThemeChoice, DEFAULT_CLICK_MODE, DEFAULT_DELAY_MS,
+    DEFAULT_HOTKEY, DEFAULT_MOUSE_BUTTON, DEFAULT_START_DELAY_MS,
+};
+
+const CONFIG_DIR_NAME: &str = "rust_auto_clicker";
+const CONFIG_FILE_NAME: &str = "config.json";
+const PRESETS_FILE_NAME: &str = "presets.json";
+
+pub const DEFAULT_PRESET_NAME: &str = "Default";
+
+fn config_file(name: &str) -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join(CONFIG_DIR_NAME).join(name))
+}
+
+// ------------------- Persisted Settings -----------------
+
+// Missing fields fall back to their defaults so older config files keep loading
+// after new options are added; unknown fields from newer versions are ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub delay_ms: u32,
+    pub hotkey: Hotkey,
+    pub stop_hotkey: Hotkey,
+    // Modifiers that must be held along with `hotkey` for it to start the clicker.
+    pub hotkey_modifiers: Vec<Modifier>,
+    pub mouse_button: MouseButtonChoice,
+    pub click_mode: ClickMode,
+    pub lifetime_clicks: u64,
+    pub preset_hotkey: Option<Hotkey>,
+    pub theme: ThemeChoice,
+    pub delay_range: DelayRange,
+    pub language: Language,
+    pub sounds_enabled: bool,
+    pub corner_failsafe: bool,
+    pub always_on_top: bool,
+    pub low_power: bool,
+    // Where the window was when the app last closed, in logical pixels.
+    pub window_position: Option<(i32, i32)>,
+    pub window_size: Option<(u32, u32)>,
+    // 0 means the clicker may run indefinitely.
+    pub max_run_ms: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            delay_ms: DEFAULT_DELAY_MS,
+            hotkey: DEFAULT_HOTKEY,
+            stop_hotkey: DEFAULT_HOTKEY,
+            hotkey_modifiers: Vec::new(),
+            mouse_button: DEFAULT_MOUSE_BUTTON,
+            click_mode: DEFAULT_CLICK_MODE,
+            lifetime_clicks: 0,
+            preset_hotkey: None,
+            theme: ThemeChoice::default(),
+            delay_range: DelayRange::default(),
+            language: Language::default(),
+            sounds_enabled: true,
+            corner_failsafe: true,
+            always_on_top: false,
+            low_power: false,
+            window_position: None,
+            window_size: None,
+            max_run_ms: 0,
+        }
+    }
+}
+
+impl Config {
+    pub fn path() -> Option<PathBuf> {
+        config_file(CONFIG_FILE_NAME)
+    }
+
+    pub fn load() -> Self {
+        match Self::path() {
+            Some(path) => Self::load_from(&path),
+            None => {
+                println!("No config directory available, using defaults.");
+                Self::default()
+            }
+        }
+    }
+
+    pub fn load_from(path: &Path) -> Self {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) => {
+                println!("Could not read config {:?} ({}), using defaults.", path, e);
+                return Self::default();
+            }
+        };
+
+        match serde_json::from_str(&contents) {
+            Ok(config) => {
+                println!("Loaded config from {:?}", path);
+                config
+            }
+            Err(e) => {
+                println!("Config {:?} is corrupt ({}), using defaults.", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    // Failures are only logged: a lost settings save should never interrupt clicking.
+    pub fn save(&self) {
+        match Self::path() {
+            Some(path) => {
+                if let Err(e) = self.save_to(&path) {
+                    println!("Failed to save config to {:?}: {}", path, e);
+                }
+            }
+            None => println!("No config directory available, settings not saved."),
+        }
+    }
+
+    pub fn save_to(&self, path: &Path) -> std::io::Result<()> {
+        write_json(path, self)
+    }
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(value).map_err(std::io::Error::other)?;
+    fs::write(path, json)
+}
+// --------------------------------------------------------
+
+// ------------------- Exported Profiles ------------------
+
+// A full setup the user can export and import. Unlike `Config` it carries the
+// click coordinates, and leaves out stats such as the lifetime click count.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    pub delay_ms: u32,
+    pub jitter_ms: u32,
+    pub start_delay_ms: u32,
+    pub hotkey: Hotkey,
+    pub stop_hotkey: Hotkey,
+    // Modifiers that must be held along with `hotkey` for it to start the clicker.
+    pub hotkey_modifiers: Vec<Modifier>,
+    pub mouse_button: MouseButtonChoice,
+    pub click_mode: ClickMode,
+    pub click_type: ClickType,
+    pub target_position: Option<(i32, i32)>,
+    pub click_radius: u32,
+    pub recorded_points: Vec<SequencePoint>,
+    pub recorded_path: Vec<(i32, i32, Duration)>,
+    // Buttons clicked in turn, one per click; empty uses `mouse_button`.
+    pub click_pattern: Vec<MouseButtonChoice>,
+    // Time between the user's own clicks, replayed in place of the delay.
+    pub recorded_rhythm: Vec<Duration>,
+    // `None` when clicks don't follow the cursor at an offset.
+    pub cursor_offset: Option<(i32, i32)>,
+    // `None` when drag mode is off.
+    pub drag: Option<DragMode>,
+    // `None` when clicking rather than pressing keys.
+    pub key_sequence: Option<KeySequence>,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self {
+            delay_ms: DEFAULT_DELAY_MS,
+            jitter_ms: 0,
+            start_delay_ms: DEFAULT_START_DELAY_MS,
+            hotkey: DEFAULT_HOTKEY,
+            stop_hotkey: DEFAULT_HOTKEY,
+            hotkey_modifiers: Vec::new(),
+            mouse_button: DEFAULT_MOUSE_BUTTON,
+            click_mode: DEFAULT_CLICK_MODE,
+            click_type: ClickType::default(),
+            target_position: None,
+            click_radius: 0,
+            recorded_points: Vec::new(),
+            recorded_path: Vec::new(),
+            click_pattern: Vec::new(),
+            recorded_rhythm: Vec::new(),
+            cursor_offset: None,
+            drag: None,
+            key_sequence: None,
+        }
+    }
+}
+
+impl Profile {
+    // Unlike the config, profile errors are returned: the user picked the file and
+    // should be told why it didn't work.
+    pub fn load_from(path: &Path) -> std::io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        serde_json::from_str(&contents).map_err(std::io::Error::other)
+    }
+
+    pub fn save_to(&self, path: &Path) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        fs::write(path, json)
+    }
+}
+// --------------------------------------------------------
+
+// ------------------- Named Presets ----------------------
+
+// The default preset is always present and can't be removed, so there is
+// always something to go back to.
+pub fn load_presets() -> HashMap<String, Profile> {
+    let mut presets: HashMap<String, Profile> = config_file(PRESETS_FILE_NAME)
+        .and_then(|path| fs::read_to_string(&path).ok())
+        .and_then(|contents| match serde_json::from_str(&contents) {
+            Ok(presets) => Some(presets),
+            Err(e) => {
+                println!("Presets file is corrupt ({}), starting with defaults.", e);
+                None
+            }
+        })
+        .unwrap_or_default();
+    presets.entry(DEFAULT_PRESET_NAME.to_string()).or_default();
+    presets
+}
+
+pub fn save_presets(presets: &HashMap<String, Profile>) {
+    let Some(path) = config_file(PRESETS_FILE_NAME) else {
+        println!("No config directory available, presets not saved.");
+        return;
+    };
+    if let Err(e) = write_json(&path, presets) {
+        println!("Failed to save presets to {:?}: {}", path, e);
+    }
+}
+// --------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Unique per process and test, so parallel runs don't share a file.
+    fn temp_path(name: &str) -> PathBuf {
+        let file = format!("{}_{}_{}.json", CONFIG_DIR_NAME, std::process::id(), name);
+        std::env::temp_dir().join(file)
+    }
+
+    #[test]
+    fn config_round_trips_through_a_file() {
+        let path = temp_path("round_trip");
+        let config = Config {
+            delay_ms: 250,
+            hotkey: Hotkey::F8,
+            stop_hotkey: Hotkey::F9,
+            hotkey_modifiers: vec![Modifier::Control, Modifier::Alt],
+            mouse_button: MouseButtonChoice::Right,
+            click_mode: ClickMode::Hold,
+            lifetime_clicks: 12_345,
+            preset_hotkey: Some(Hotkey::F10),
+            theme: ThemeChoice::Dark,
+            delay_range: DelayRange::TenSeconds,
+            language: Language::German,
+            sounds_enabled: false,
+            corner_failsafe: false,
+            always_on_top: true,
+            low_power: true,
+            window_position: Some((-1280, 40)),
+            window_size: Some((900, 700)),
+            max_run_ms: 60_000,
+        };
+        assert_ne!(config, Config::default());
+
+        config.save_to(&path).unwrap();
+        let loaded = Config::load_from(&path);
+        let _ = fs::remove_file(&path);
+        assert_eq!(loaded, config);
+    }
+
+    #[test]
+    fn missing_fields_fall_back_to_defaults() {
+        let path = temp_path("missing_fields");
+        fs::write(&path, r#"{ "delay_ms": 250, "theme": "Dark" }"#).unwrap();
+        let loaded = Config::load_from(&path);
+        let _ = fs::remove_file(&path);
+        assert_eq!(loaded, Config { delay_ms: 250, theme: ThemeChoice::Dark, ..Config::default() });
+    }
+
+    #[test]
+    fn corrupt_config_falls_back_to_defaults() {
+        let path = temp_path("corrupt");
+        fs::write(&path, "{ not json").unwrap();
+        let loaded = Config::load_from(&path);
+        let _ = fs::remove_file(&path);
+        assert_eq!(loaded, Config::default());
+    }
+
+    #[test]
+    fn unreadable_config_falls_back_to_defaults() {
+        // A directory can't be read as a file, and a missing path can't be read at all.
+        let dir = temp_path("unreadable");
+        fs::create_dir_all(&dir).unwrap();
+        let loaded = Config::load_from(&dir);
+        let _ = fs::remove_dir(&dir);
+        assert_eq!(loaded, Config::default());
+        assert_eq!(Config::load_from(&temp_path("missing")), Config::default());
+    }
+}
@@ -0,0 +1,32 @@
+// ------------------- Window Focus -----------------------
+
+// Brings the top-level window under the given global coordinates to the
+// foreground. Returns whether a window was activated.
+#[cfg(target_os = "windows")]
+pub fn focus_window_at(x: i32, y: i32) -> bool {
+    use windows_sys::Win32::Foundation::POINT;
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        GetAncestor, SetForegroundWindow, WindowFromPoint, GA_ROOT,
+    };
+
+    // SAFETY: plain Win32 calls on handles we just queried; a null handle is
+    // checked before use.
+    unsafe {
+        let hwnd = WindowFromPoint(POINT { x, y });
+        if hwnd == 0 {
+            return false;
+        }
+        let root = GetAncestor(hwnd, GA_ROOT);
+        SetForegroundWindow(if root == 0 { hwnd } else { root }) != 0
+    }
+}
+
+// Only implemented for Windows so far; elsewhere the click itself usually
+// focuses the window anyway.
+#[cfg(not(target_os = "windows"))]
+pub fn focus_window_at(_x: i32, _y: i32) -> bool {
+    false
+}
+
+pub const SUPPORTED: bool = cfg!(target_os = "windows");
+// --------------------------------------------------------
@@ -0,0 +1,556 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+// ------------------- Language Enum ----------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::German];
+
+    // Adding a language means adding a variant, a table function below and a
+    // line here; `view()` never changes.
+    fn table(self) -> fn(Label) -> &'static str {
+        match self {
+            Language::English => english,
+            Language::German => german,
+        }
+    }
+
+    pub fn get(self, label: Label) -> &'static str {
+        (self.table())(label)
+    }
+
+    // Replaces each `{}` in the label with the next argument, so translations are
+    // free to reorder the surrounding words.
+    pub fn fill(self, label: Label, args: &[&dyn Display]) -> String {
+        let mut parts = self.get(label).split("{}");
+        let mut filled = String::from(parts.next().unwrap_or_default());
+        let mut args = args.iter();
+        for part in parts {
+            if let Some(arg) = args.next() {
+                filled.push_str(&arg.to_string());
+            }
+            filled.push_str(part);
+        }
+        filled
+    }
+}
+
+// Shown in each language's own name so it can be found without reading the
+// current one.
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Language::English => "English",
+            Language::German => "Deutsch",
+        };
+        write!(f, "{}", name)
+    }
+}
+// --------------------------------------------------------
+
+// ------------------- UI Strings -------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Label {
+    StatusStartingIn,
+    StatusRunning,
+    StatusPaused,
+    StatusStopped,
+    StatusDisarmed,
+    Measured,
+    TotalClicks,
+    SessionClicks,
+    LifetimeClicks,
+    Reset,
+    Start,
+    Stop,
+    Pause,
+    Resume,
+    Arm,
+    Disarm,
+    TestClick,
+    TestClickPending,
+    TestClickSent,
+    Clear,
+    Delete,
+    Cancel,
+    StartAnyway,
+    ConfirmFastStart,
+    SoundsOnStartStop,
+    VerboseLogging,
+    AlwaysOnTop,
+    LowPower,
+    RefreshEvery,
+    SelectHotkey,
+    SelectStopHotkey,
+    NoHotkey,
+    StartHotkey,
+    StopHotkey,
+    NextPresetHotkey,
+    SetHotkey,
+    CapturingStartHotkey,
+    CapturingStopHotkey,
+    PanicKeyHint,
+    CornerFailsafe,
+    SelectMouseButton,
+    SelectClickMode,
+    SelectClickType,
+    SelectKey,
+    SelectPreset,
+    SelectLanguage,
+    SpeedDelay,
+    SpeedCps,
+    IntervalNoData,
+    Interval,
+    Jitter,
+    JitterIgnored,
+    Humanize,
+    StdDev,
+    RandomRange,
+    Range,
+    HoldTap,
+    Hold,
+    StartDelay,
+    MaxClicks,
+    Unlimited,
+    RemainingUnlimited,
+    Remaining,
+    ClickedOf,
+    MaxRun,
+    MaxRunUnlimited,
+    AutoStopIn,
+    AutoStopAfterClicks,
+    AutoStopAtSchedule,
+    Target,
+    TargetCursor,
+    ClickAtX,
+    CursorPlaceholder,
+    CapturePosition,
+    PickPosition,
+    PickingPosition,
+    CursorOffset,
+    ClickPattern,
+    PatternInvalid,
+    RandomRadius,
+    FocusTarget,
+    FocusTargetWindowsOnly,
+    TargetOverlay,
+    TargetOverlayWindowsOnly,
+    OnlyInWindow,
+    AnyWindow,
+    SequenceNone,
+    Sequence,
+    AddCurrentPosition,
+    PointClicks,
+    PointDwell,
+    Remove,
+    PathRecording,
+    PathNone,
+    Path,
+    PathReplayed,
+    RecordPath,
+    Play,
+    RhythmRecording,
+    RhythmNone,
+    Rhythm,
+    RhythmReplayed,
+    RecordRhythm,
+    Replay,
+    Ramp,
+    RampFrom,
+    RampTo,
+    RampOver,
+    RampSwapEvery,
+    RampCurrent,
+    BurstMode,
+    BurstSize,
+    BurstPause,
+    KeyPress,
+    PressCount,
+    PressCountInvalid,
+    NoFinishKey,
+    RepeatCycle,
+    Scroll,
+    ScrollLines,
+    DragMode,
+    DragFrom,
+    DragFromUnset,
+    DragTo,
+    DragToUnset,
+    SetDragFrom,
+    SetDragTo,
+    DragSteps,
+    HoldModifiers,
+    StartCombo,
+    Jiggle,
+    JiggleEvery,
+    StartsIn,
+    StopsIn,
+    NoSchedule,
+    StartInMinutes,
+    RunForMinutes,
+    UntilStopped,
+    Schedule,
+    CancelSchedule,
+    PresetName,
+    SaveAs,
+    ExportProfile,
+    About,
+    AboutVersion,
+    AboutPlatform,
+    AboutInput,
+    AboutListener,
+    BackendReady,
+    BackendFailed,
+    ListenerActive,
+    ListenerWaiting,
+    ImportProfile,
+}
+
+fn english(label: Label) -> &'static str {
+    match label {
+        Label::StatusStartingIn => "Starting in {}s…",
+        Label::StatusRunning => "Auto Clicker is RUNNING",
+        Label::StatusPaused => "Auto Clicker is PAUSED",
+        Label::StatusStopped => "Auto Clicker is STOPPED",
+        Label::StatusDisarmed => "Auto Clicker is DISARMED (hotkeys and Start are ignored)",
+        Label::Measured => "Measured: {} CPS",
+        Label::TotalClicks => "Total clicks: {} in {} s (avg {} CPS)",
+        Label::SessionClicks => "This run: {} clicks",
+        Label::LifetimeClicks => "Lifetime clicks: {}",
+        Label::Reset => "Reset",
+        Label::Start => "Start",
+        Label::Stop => "Stop",
+        Label::Pause => "Pause",
+        Label::Resume => "Resume",
+        Label::Arm => "Arm",
+        Label::Disarm => "Disarm",
+        Label::TestClick => "Test click",
+        Label::TestClickPending => "Test click in {} s, move the mouse where it should land…",
+        Label::TestClickSent => "Test click sent: {} button at ({}, {})",
+        Label::Clear => "Clear",
+        Label::Delete => "Delete",
+        Label::Cancel => "Cancel",
+        Label::StartAnyway => "Start anyway",
+        Label::ConfirmFastStart => {
+            "A delay of {} ms is very fast and can make the machine hard to use. Start anyway?"
+        }
+        Label::SoundsOnStartStop => "Sound on start/stop",
+        Label::VerboseLogging => "Verbose click logging",
+        Label::AlwaysOnTop => "Always on top",
+        Label::LowPower => "Low power when idle",
+        Label::RefreshEvery => "Window refreshes every {}",
+        Label::SelectHotkey => "Select Hotkey",
+        Label::SelectStopHotkey => "Select Stop Hotkey",
+        Label::NoHotkey => "None",
+        Label::StartHotkey => "Start:",
+        Label::StopHotkey => "Stop:",
+        Label::NextPresetHotkey => "Next preset:",
+        Label::SetHotkey => "Set…",
+        Label::CapturingStartHotkey => {
+            "Press any key or mouse button for the start hotkey, holding Shift, Ctrl or Alt \
+             for a combination (Esc cancels)…"
+        }
+        Label::CapturingStopHotkey => {
+            "Press any key or mouse button for the stop hotkey (Esc cancels)…"
+        }
+        Label::PanicKeyHint => "Esc always stops the clicker.",
+        Label::CornerFailsafe => "Also stop when the mouse is moved into the top-left corner",
+        Label::SelectMouseButton => "Select Mouse Button",
+        Label::SelectClickMode => "Select Click Mode",
+        Label::SelectClickType => "Select Click Type",
+        Label::SelectKey => "Select Key",
+        Label::SelectPreset => "Select Preset",
+        Label::SelectLanguage => "Select Language",
+        Label::SpeedDelay => "Delay: {}",
+        Label::SpeedCps => "Speed: {} CPS (~{} ms)",
+        Label::IntervalNoData => "Interval: configured {} ms / actual -",
+        Label::Interval => "Interval: configured {} ms / actual {} ms, ±{} ms jitter",
+        Label::Jitter => "Jitter: ±{} ms",
+        Label::JitterIgnored => "Jitter: ±{} ms (ignored while humanize is on)",
+        Label::Humanize => "Humanize: normally distributed delay (replaces jitter)",
+        Label::StdDev => "Std dev: {} ms",
+        Label::RandomRange => {
+            "Random range: uniform delay between min and max (overrides the above)"
+        }
+        Label::Range => "Range: {} - {} ms",
+        Label::HoldTap => "Hold: tap (instant release)",
+        Label::Hold => "Hold: {} ms",
+        Label::StartDelay => "Start delay: {} ms",
+        Label::MaxClicks => "Max clicks:",
+        Label::Unlimited => "Unlimited",
+        Label::RemainingUnlimited => "Remaining clicks: unlimited",
+        Label::Remaining => "Remaining clicks: {}",
+        Label::ClickedOf => "Clicked {} of {}",
+        Label::MaxRun => "Stop after running for: {} min",
+        Label::MaxRunUnlimited => "Stop after running for: unlimited",
+        Label::AutoStopIn => "Auto-stop in {} (max run time)",
+        Label::AutoStopAfterClicks => "Auto-stop after {} more clicks (~{})",
+        Label::AutoStopAtSchedule => "Auto-stop in {} (scheduled)",
+        Label::Target => "Target: ({}, {})",
+        Label::TargetCursor => "Target: cursor",
+        Label::ClickAtX => "Click at X:",
+        Label::CursorPlaceholder => "cursor",
+        Label::CapturePosition => "Capture current position",
+        Label::PickPosition => "Pick location",
+        Label::PickingPosition => "Click anywhere to pick...",
+        Label::CursorOffset => "Click at an offset from the cursor (when no target is set)",
+        Label::ClickPattern => "Button pattern (L/R/M, empty uses the selected button):",
+        Label::PatternInvalid => "Unknown button {} in pattern; use L, R or M.",
+        Label::RandomRadius => "Random radius: {} px",
+        Label::FocusTarget => "Bring the window under the target to the front first",
+        Label::FocusTargetWindowsOnly => {
+            "Bring the window under the target to the front first (Windows only)"
+        }
+        Label::TargetOverlay => "Show a crosshair at the target",
+        Label::TargetOverlayWindowsOnly => "Show a crosshair at the target (Windows only)",
+        Label::OnlyInWindow => "Only click in window:",
+        Label::AnyWindow => "any window",
+        Label::SequenceNone => "Sequence: none recorded",
+        Label::Sequence => "Sequence: {} points, clicked in order",
+        Label::AddCurrentPosition => "Add current position",
+        Label::PointClicks => "clicks:",
+        Label::PointDwell => "then wait:",
+        Label::Remove => "Remove",
+        Label::PathRecording => "Path: recording… {} samples",
+        Label::PathNone => "Path: none recorded",
+        Label::Path => "Path: {} samples over {} s",
+        Label::PathReplayed => ", replayed while running",
+        Label::RecordPath => "Record path",
+        Label::Play => "Play",
+        Label::RhythmRecording => "Rhythm: recording… left-click along, {} intervals",
+        Label::RhythmNone => "Rhythm: none recorded",
+        Label::Rhythm => "Rhythm: {} intervals over {} s",
+        Label::RhythmReplayed => ", used instead of the delay",
+        Label::RecordRhythm => "Record rhythm",
+        Label::Replay => "Replay",
+        Label::Ramp => "Ramp",
+        Label::RampFrom => "Ramp from {} ms",
+        Label::RampTo => "Ramp to {} ms",
+        Label::RampOver => "Ramp over {} s",
+        Label::RampSwapEvery => "Swap every {} s",
+        Label::RampCurrent => "Current delay: {} ms",
+        Label::BurstMode => "Burst mode",
+        Label::BurstSize => "Burst size: {} clicks",
+        Label::BurstPause => "Pause between bursts: {} s",
+        Label::KeyPress => "Press a key instead of clicking",
+        Label::PressCount => "times, then",
+        Label::PressCountInvalid => "The count must be at least 1",
+        Label::NoFinishKey => "No finishing key",
+        Label::RepeatCycle => "Repeat",
+        Label::Scroll => "Scroll instead of clicking",
+        Label::HoldModifiers => "Hold while clicking:",
+        Label::StartCombo => "Start hotkey needs:",
+        Label::ScrollLines => "{} lines",
+        Label::DragMode => "Drag mode",
+        Label::DragFrom => "From ({}, {})",
+        Label::DragFromUnset => "From: not set",
+        Label::DragTo => "to ({}, {})",
+        Label::DragToUnset => "to: not set",
+        Label::SetDragFrom => "Set start",
+        Label::SetDragTo => "Set end",
+        Label::DragSteps => "{} steps",
+        Label::Jiggle => "Anti-AFK jiggle instead of clicking",
+        Label::JiggleEvery => "every {} s",
+        Label::StartsIn => "Starts in {}",
+        Label::StopsIn => "Stops in {}",
+        Label::NoSchedule => "No schedule",
+        Label::StartInMinutes => "Start in (min):",
+        Label::RunForMinutes => "Run for (min):",
+        Label::UntilStopped => "until stopped",
+        Label::Schedule => "Schedule",
+        Label::CancelSchedule => "Cancel schedule",
+        Label::PresetName => "Preset name",
+        Label::SaveAs => "Save as…",
+        Label::ExportProfile => "Export Profile",
+        Label::About => "About / diagnostics",
+        Label::AboutVersion => "Version {}",
+        Label::AboutPlatform => "Platform: {}",
+        Label::AboutInput => "Input backend (enigo): {}",
+        Label::AboutListener => "Hotkey listener (rdev): {}",
+        Label::BackendReady => "ready",
+        Label::BackendFailed => "failed, see the message at the top",
+        Label::ListenerActive => "active",
+        Label::ListenerWaiting => "started, no input seen yet",
+        Label::ImportProfile => "Import Profile",
+    }
+}
+
+fn german(label: Label) -> &'static str {
+    match label {
+        Label::StatusStartingIn => "Start in {}s…",
+        Label::StatusRunning => "Auto Clicker LÄUFT",
+        Label::StatusPaused => "Auto Clicker ist PAUSIERT",
+        Label::StatusStopped => "Auto Clicker ist GESTOPPT",
+        Label::StatusDisarmed => "Auto Clicker ist ENTSCHÄRFT (Hotkeys und Start sind aus)",
+        Label::Measured => "Gemessen: {} CPS",
+        Label::TotalClicks => "Klicks gesamt: {} in {} s (Ø {} CPS)",
+        Label::SessionClicks => "Dieser Lauf: {} Klicks",
+        Label::LifetimeClicks => "Klicks insgesamt: {}",
+        Label::Reset => "Zurücksetzen",
+        Label::Start => "Start",
+        Label::Stop => "Stopp",
+        Label::Pause => "Pause",
+        Label::Resume => "Fortsetzen",
+        Label::Arm => "Scharf schalten",
+        Label::Disarm => "Entschärfen",
+        Label::TestClick => "Testklick",
+        Label::TestClickPending => "Testklick in {} s, Maus zur gewünschten Stelle bewegen…",
+        Label::TestClickSent => "Testklick gesendet: {} Taste bei ({}, {})",
+        Label::Clear => "Leeren",
+        Label::Delete => "Löschen",
+        Label::Cancel => "Abbrechen",
+        Label::StartAnyway => "Trotzdem starten",
+        Label::ConfirmFastStart => {
+            "Eine Verzögerung von {} ms ist sehr kurz und kann den Rechner schwer bedienbar \
+             machen. Trotzdem starten?"
+        }
+        Label::SoundsOnStartStop => "Ton bei Start/Stopp",
+        Label::VerboseLogging => "Ausführliches Klick-Log",
+        Label::AlwaysOnTop => "Immer im Vordergrund",
+        Label::LowPower => "Stromsparen im Leerlauf",
+        Label::RefreshEvery => "Fenster aktualisiert alle {}",
+        Label::SelectHotkey => "Hotkey wählen",
+        Label::SelectStopHotkey => "Stopp-Hotkey wählen",
+        Label::NoHotkey => "Keiner",
+        Label::StartHotkey => "Start:",
+        Label::StopHotkey => "Stopp:",
+        Label::NextPresetHotkey => "Nächstes Preset:",
+        Label::SetHotkey => "Festlegen…",
+        Label::CapturingStartHotkey => {
+            "Beliebige Taste oder Maustaste für den Start-Hotkey drücken, für eine \
+             Kombination dabei Umschalt, Strg oder Alt halten (Esc bricht ab)…"
+        }
+        Label::CapturingStopHotkey => {
+            "Beliebige Taste oder Maustaste für den Stopp-Hotkey drücken (Esc bricht ab)…"
+        }
+        Label::PanicKeyHint => "Esc stoppt den Clicker immer.",
+        Label::CornerFailsafe => "Auch stoppen, wenn die Maus in die linke obere Ecke fährt",
+        Label::SelectMouseButton => "Maustaste wählen",
+        Label::SelectClickMode => "Klickmodus wählen",
+        Label::SelectClickType => "Klickart wählen",
+        Label::SelectKey => "Taste wählen",
+        Label::SelectPreset => "Preset wählen",
+        Label::SelectLanguage => "Sprache wählen",
+        Label::SpeedDelay => "Verzögerung: {}",
+        Label::SpeedCps => "Tempo: {} CPS (~{} ms)",
+        Label::IntervalNoData => "Intervall: eingestellt {} ms / tatsächlich -",
+        Label::Interval => "Intervall: eingestellt {} ms / tatsächlich {} ms, ±{} ms Schwankung",
+        Label::Jitter => "Jitter: ±{} ms",
+        Label::JitterIgnored => "Jitter: ±{} ms (ignoriert, solange Humanisieren an ist)",
+        Label::Humanize => "Humanisieren: normalverteilte Verzögerung (ersetzt Jitter)",
+        Label::StdDev => "Standardabweichung: {} ms",
+        Label::RandomRange => {
+            "Zufallsbereich: gleichverteilte Verzögerung zwischen Min. und Max. (hat Vorrang)"
+        }
+        Label::Range => "Bereich: {} - {} ms",
+        Label::HoldTap => "Halten: antippen (sofort loslassen)",
+        Label::Hold => "Halten: {} ms",
+        Label::StartDelay => "Startverzögerung: {} ms",
+        Label::MaxClicks => "Max. Klicks:",
+        Label::Unlimited => "Unbegrenzt",
+        Label::RemainingUnlimited => "Verbleibende Klicks: unbegrenzt",
+        Label::Remaining => "Verbleibende Klicks: {}",
+        Label::ClickedOf => "{} von {} geklickt",
+        Label::MaxRun => "Stoppen nach einer Laufzeit von: {} min",
+        Label::MaxRunUnlimited => "Stoppen nach einer Laufzeit von: unbegrenzt",
+        Label::AutoStopIn => "Automatischer Stopp in {} (max. Laufzeit)",
+        Label::AutoStopAfterClicks => "Automatischer Stopp nach {} weiteren Klicks (~{})",
+        Label::AutoStopAtSchedule => "Automatischer Stopp in {} (geplant)",
+        Label::Target => "Ziel: ({}, {})",
+        Label::TargetCursor => "Ziel: Mauszeiger",
+        Label::ClickAtX => "Klicken bei X:",
+        Label::CursorPlaceholder => "Zeiger",
+        Label::CapturePosition => "Aktuelle Position übernehmen",
+        Label::PickPosition => "Position wählen",
+        Label::PickingPosition => "Zum Wählen irgendwo klicken...",
+        Label::CursorOffset => "Versetzt zum Mauszeiger klicken (wenn kein Ziel gesetzt ist)",
+        Label::ClickPattern => "Tastenmuster (L/R/M, leer nutzt die gewählte Taste):",
+        Label::PatternInvalid => "Unbekannte Taste {} im Muster; erlaubt sind L, R und M.",
+        Label::RandomRadius => "Zufallsradius: {} px",
+        Label::FocusTarget => "Fenster unter dem Ziel zuerst in den Vordergrund holen",
+        Label::FocusTargetWindowsOnly => {
+            "Fenster unter dem Ziel zuerst in den Vordergrund holen (nur Windows)"
+        }
+        Label::TargetOverlay => "Fadenkreuz am Ziel anzeigen",
+        Label::TargetOverlayWindowsOnly => "Fadenkreuz am Ziel anzeigen (nur Windows)",
+        Label::OnlyInWindow => "Nur in Fenster klicken:",
+        Label::AnyWindow => "beliebiges Fenster",
+        Label::SequenceNone => "Sequenz: keine aufgezeichnet",
+        Label::Sequence => "Sequenz: {} Punkte, der Reihe nach geklickt",
+        Label::AddCurrentPosition => "Aktuelle Position hinzufügen",
+        Label::PointClicks => "Klicks:",
+        Label::PointDwell => "danach warten:",
+        Label::Remove => "Entfernen",
+        Label::PathRecording => "Pfad: Aufnahme… {} Punkte",
+        Label::PathNone => "Pfad: keiner aufgezeichnet",
+        Label::Path => "Pfad: {} Punkte über {} s",
+        Label::PathReplayed => ", wird beim Klicken abgespielt",
+        Label::RecordPath => "Pfad aufnehmen",
+        Label::Play => "Abspielen",
+        Label::RhythmRecording => "Rhythmus: Aufnahme… links mitklicken, {} Abstände",
+        Label::RhythmNone => "Rhythmus: keiner aufgezeichnet",
+        Label::Rhythm => "Rhythmus: {} Abstände über {} s",
+        Label::RhythmReplayed => ", ersetzt die Verzögerung",
+        Label::RecordRhythm => "Rhythmus aufnehmen",
+        Label::Replay => "Wiedergeben",
+        Label::Ramp => "Rampe",
+        Label::RampFrom => "Rampe von {} ms",
+        Label::RampTo => "Rampe bis {} ms",
+        Label::RampOver => "Rampe über {} s",
+        Label::RampSwapEvery => "Wechsel alle {} s",
+        Label::RampCurrent => "Aktuelle Verzögerung: {} ms",
+        Label::BurstMode => "Salvenmodus",
+        Label::BurstSize => "Salvengröße: {} Klicks",
+        Label::BurstPause => "Pause zwischen Salven: {} s",
+        Label::KeyPress => "Taste drücken statt klicken",
+        Label::PressCount => "mal, dann",
+        Label::PressCountInvalid => "Die Anzahl muss mindestens 1 sein",
+        Label::NoFinishKey => "Keine Abschlusstaste",
+        Label::RepeatCycle => "Wiederholen",
+        Label::Scroll => "Scrollen statt klicken",
+        Label::HoldModifiers => "Beim Klicken halten:",
+        Label::StartCombo => "Start-Hotkey braucht:",
+        Label::ScrollLines => "{} Zeilen",
+        Label::DragMode => "Ziehen",
+        Label::DragFrom => "Von ({}, {})",
+        Label::DragFromUnset => "Von: nicht gesetzt",
+        Label::DragTo => "nach ({}, {})",
+        Label::DragToUnset => "nach: nicht gesetzt",
+        Label::SetDragFrom => "Start setzen",
+        Label::SetDragTo => "Ende setzen",
+        Label::DragSteps => "{} Schritte",
+        Label::Jiggle => "Anti-AFK-Mausbewegung statt klicken",
+        Label::JiggleEvery => "alle {} s",
+        Label::StartsIn => "Startet in {}",
+        Label::StopsIn => "Stoppt in {}",
+        Label::NoSchedule => "Kein Zeitplan",
+        Label::StartInMinutes => "Start in (Min.):",
+        Label::RunForMinutes => "Laufzeit (Min.):",
+        Label::UntilStopped => "bis gestoppt",
+        Label::Schedule => "Planen",
+        Label::CancelSchedule => "Zeitplan abbrechen",
+        Label::PresetName => "Preset-Name",
+        Label::SaveAs => "Speichern als…",
+        Label::ExportProfile => "Profil exportieren",
+        Label::About => "Über / Diagnose",
+        Label::AboutVersion => "Version {}",
+        Label::AboutPlatform => "Plattform: {}",
+        Label::AboutInput => "Eingabe-Backend (enigo): {}",
+        Label::AboutListener => "Hotkey-Listener (rdev): {}",
+        Label::BackendReady => "bereit",
+        Label::BackendFailed => "fehlgeschlagen, siehe Meldung oben",
+        Label::ListenerActive => "aktiv",
+        Label::ListenerWaiting => "gestartet, noch keine Eingabe gesehen",
+        Label::ImportProfile => "Profil importieren",
+    }
+}
+// --------------------------------------------------------
@@ -0,0 +1,43 @@
+use log::{LevelFilter, Log, Metadata, Record};
+
+// Set to anything (e.g. `RUST_AUTO_CLICKER_VERBOSE=1`) to start with per-click
+// logging on.
+const VERBOSE_ENV_VAR: &str = "RUST_AUTO_CLICKER_VERBOSE";
+
+// ------------------- Click Loop Logging -----------------
+
+// Per-click messages are logged at debug level. The `log` macros check the level
+// before formatting anything, so with verbose logging off the click loop never
+// pays for building those strings.
+struct StdoutLogger;
+
+impl Log for StdoutLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            println!("[AutoClicker] {}", record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StdoutLogger = StdoutLogger;
+
+// Returns whether verbose logging starts enabled.
+pub fn init() -> bool {
+    let verbose = std::env::var_os(VERBOSE_ENV_VAR).is_some();
+    if let Err(e) = log::set_logger(&LOGGER) {
+        println!("Failed to install logger: {}", e);
+    }
+    set_verbose(verbose);
+    verbose
+}
+
+pub fn set_verbose(verbose: bool) {
+    log::set_max_level(if verbose { LevelFilter::Debug } else { LevelFilter::Info });
+}
+// --------------------------------------------------------
@@ -1,36 +1,148 @@
+mod api;
+mod cli;
+mod config;
+mod focus;
+mod i18n;
+mod logging;
+mod overlay;
+mod screen;
+mod sound;
+mod tray;
+
 use iced::{
-    executor, Application, Command, Element, Length, Settings,
+    executor, Application, Color, Command, Element, Length, Settings, Subscription,
     theme,
-    widget::{button, column, row, text, slider, PickList},
+    widget::{
+        button, checkbox, column, progress_bar, row, scrollable, text, text_input, slider,
+        PickList,
+    },
 };
 use std::sync::{
     Arc,
     atomic::{AtomicBool, AtomicUsize, Ordering},
-    Mutex,
+    Condvar, Mutex,
 };
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::thread;
-use std::time::Duration;
-use enigo::{Enigo, MouseControllable, MouseButton};
-use rdev::{listen, EventType, Key};
+use std::time::{Duration, Instant};
+use device_query::{DeviceQuery, DeviceState};
+use enigo::{Enigo, KeyboardControllable, MouseControllable, MouseButton};
+use rdev::{listen, Button, EventType, Key};
+use rand::Rng;
+use rand_distr::{Distribution, Normal};
+use serde::{Deserialize, Serialize};
+
+use config::{Config, Profile, DEFAULT_PRESET_NAME};
+use i18n::{Label, Language};
 
 // ------------------- Hotkey Enum ------------------------
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 enum Hotkey {
     F1,
     F2,
     F3,
     F4,
     F5,
+    #[default]
     F6,
     F7,
     F8,
     F9,
     F10,
+    F11,
+    F12,
+    Insert,
+    Delete,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Numpad0,
+    Numpad1,
+    Numpad2,
+    Numpad3,
+    Numpad4,
+    Numpad5,
+    Numpad6,
+    Numpad7,
+    Numpad8,
+    Numpad9,
+    MouseMiddle,
+    MouseBack,
+    MouseForward,
+    // Anything else, bound by pressing it with "Set…".
+    Custom(HotkeyTrigger),
+}
+
+// Whatever rdev reports for a hotkey: either a keyboard key or a mouse button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+enum HotkeyTrigger {
+    Key(Key),
+    Button(Button),
+}
+
+impl HotkeyTrigger {
+    fn from_event(event_type: EventType) -> Option<(HotkeyTrigger, bool)> {
+        match event_type {
+            EventType::KeyPress(key) => Some((HotkeyTrigger::Key(key), true)),
+            EventType::KeyRelease(key) => Some((HotkeyTrigger::Key(key), false)),
+            EventType::ButtonPress(button) => Some((HotkeyTrigger::Button(button), true)),
+            EventType::ButtonRelease(button) => Some((HotkeyTrigger::Button(button), false)),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for HotkeyTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HotkeyTrigger::Button(Button::Unknown(code)) => write!(f, "Mouse Button {}", code),
+            HotkeyTrigger::Button(button) => write!(f, "Mouse {:?}", button),
+            HotkeyTrigger::Key(Key::Unknown(code)) => write!(f, "Key {}", code),
+            // rdev names letters and digits "KeyA" and "Num1".
+            HotkeyTrigger::Key(key) => {
+                let name = format!("{:?}", key);
+                match name.strip_prefix("Key").or_else(|| name.strip_prefix("Num")) {
+                    Some(short) if short.len() == 1 => write!(f, "{}", short),
+                    _ => write!(f, "{}", name),
+                }
+            }
+        }
+    }
+}
+
+// Which hotkey the next key press is bound to while capturing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HotkeyCapture {
+    Start,
+    Stop,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CapturedHotkey {
+    capture: HotkeyCapture,
+    hotkey: Hotkey,
+    // Held during the capture; only the start hotkey uses them.
+    modifiers: Vec<Modifier>,
+}
+
+// rdev reports the side ("back"/"forward") buttons as `Button::Unknown` with the
+// platform's raw button number. macOS isn't covered: rdev 0.5 only reports the
+// left and right buttons there.
+#[cfg(target_os = "windows")]
+const MOUSE_BACK_CODE: u8 = 1;
+#[cfg(target_os = "windows")]
+const MOUSE_FORWARD_CODE: u8 = 2;
+#[cfg(not(target_os = "windows"))]
+const MOUSE_BACK_CODE: u8 = 8;
+#[cfg(not(target_os = "windows"))]
+const MOUSE_FORWARD_CODE: u8 = 9;
+
 impl Hotkey {
-    const ALL: [Hotkey; 10] = [
+    // Grouped as function keys, navigation keys, the numpad, then mouse buttons.
+    // The left button is deliberately missing: it's what usually gets auto-clicked.
+    const ALL: [Hotkey; 31] = [
         Hotkey::F1,
         Hotkey::F2,
         Hotkey::F3,
@@ -41,10 +153,31 @@ impl Hotkey {
         Hotkey::F8,
         Hotkey::F9,
         Hotkey::F10,
+        Hotkey::F11,
+        Hotkey::F12,
+        Hotkey::Insert,
+        Hotkey::Delete,
+        Hotkey::Home,
+        Hotkey::End,
+        Hotkey::PageUp,
+        Hotkey::PageDown,
+        Hotkey::Numpad0,
+        Hotkey::Numpad1,
+        Hotkey::Numpad2,
+        Hotkey::Numpad3,
+        Hotkey::Numpad4,
+        Hotkey::Numpad5,
+        Hotkey::Numpad6,
+        Hotkey::Numpad7,
+        Hotkey::Numpad8,
+        Hotkey::Numpad9,
+        Hotkey::MouseMiddle,
+        Hotkey::MouseBack,
+        Hotkey::MouseForward,
     ];
 
-    fn to_rdev_key(self) -> Key {
-        match self {
+    fn to_trigger(self) -> HotkeyTrigger {
+        let key = match self {
             Hotkey::F1 => Key::F1,
             Hotkey::F2 => Key::F2,
             Hotkey::F3 => Key::F3,
@@ -55,264 +188,4696 @@ impl Hotkey {
             Hotkey::F8 => Key::F8,
             Hotkey::F9 => Key::F9,
             Hotkey::F10 => Key::F10,
+            Hotkey::F11 => Key::F11,
+            Hotkey::F12 => Key::F12,
+            Hotkey::Insert => Key::Insert,
+            Hotkey::Delete => Key::Delete,
+            Hotkey::Home => Key::Home,
+            Hotkey::End => Key::End,
+            Hotkey::PageUp => Key::PageUp,
+            Hotkey::PageDown => Key::PageDown,
+            Hotkey::Numpad0 => Key::Kp0,
+            Hotkey::Numpad1 => Key::Kp1,
+            Hotkey::Numpad2 => Key::Kp2,
+            Hotkey::Numpad3 => Key::Kp3,
+            Hotkey::Numpad4 => Key::Kp4,
+            Hotkey::Numpad5 => Key::Kp5,
+            Hotkey::Numpad6 => Key::Kp6,
+            Hotkey::Numpad7 => Key::Kp7,
+            Hotkey::Numpad8 => Key::Kp8,
+            Hotkey::Numpad9 => Key::Kp9,
+            Hotkey::MouseMiddle => return HotkeyTrigger::Button(Button::Middle),
+            Hotkey::MouseBack => return HotkeyTrigger::Button(Button::Unknown(MOUSE_BACK_CODE)),
+            Hotkey::MouseForward => {
+                return HotkeyTrigger::Button(Button::Unknown(MOUSE_FORWARD_CODE));
+            }
+            Hotkey::Custom(trigger) => return trigger,
+        };
+        HotkeyTrigger::Key(key)
+    }
+
+    // Prefers a named variant so the dropdowns show a captured F6 as selected.
+    fn from_trigger(trigger: HotkeyTrigger) -> Hotkey {
+        Hotkey::ALL
+            .into_iter()
+            .find(|hotkey| hotkey.to_trigger() == trigger)
+            .unwrap_or(Hotkey::Custom(trigger))
+    }
+
+    fn matches(self, trigger: HotkeyTrigger) -> bool {
+        if trigger == self.to_trigger() {
+            return true;
+        }
+        // rdev 0.5 doesn't translate the navigation and numpad keys on macOS and
+        // reports them as `Key::Unknown(keycode)`, so compare the raw keycode there.
+        #[cfg(target_os = "macos")]
+        if let HotkeyTrigger::Key(Key::Unknown(code)) = trigger {
+            return self.macos_keycode() == Some(code);
+        }
+        false
+    }
+
+    // The auto-clicker's own synthetic clicks show up in rdev as well, so a mouse
+    // hotkey must never be the button that is being clicked.
+    fn conflicts_with(self, button: MouseButtonChoice) -> bool {
+        self.to_trigger() == HotkeyTrigger::Button(button.to_rdev_button())
+    }
+
+    #[cfg(target_os = "macos")]
+    fn macos_keycode(self) -> Option<u32> {
+        match self {
+            Hotkey::Insert => Some(114),
+            Hotkey::Delete => Some(117),
+            Hotkey::Home => Some(115),
+            Hotkey::End => Some(119),
+            Hotkey::PageUp => Some(116),
+            Hotkey::PageDown => Some(121),
+            Hotkey::Numpad0 => Some(82),
+            Hotkey::Numpad1 => Some(83),
+            Hotkey::Numpad2 => Some(84),
+            Hotkey::Numpad3 => Some(85),
+            Hotkey::Numpad4 => Some(86),
+            Hotkey::Numpad5 => Some(87),
+            Hotkey::Numpad6 => Some(88),
+            Hotkey::Numpad7 => Some(89),
+            Hotkey::Numpad8 => Some(91),
+            Hotkey::Numpad9 => Some(92),
+            _ => None,
         }
     }
 }
 
 impl std::fmt::Display for Hotkey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hotkey::PageUp => write!(f, "Page Up"),
+            Hotkey::PageDown => write!(f, "Page Down"),
+            Hotkey::Numpad0 => write!(f, "Numpad 0"),
+            Hotkey::Numpad1 => write!(f, "Numpad 1"),
+            Hotkey::Numpad2 => write!(f, "Numpad 2"),
+            Hotkey::Numpad3 => write!(f, "Numpad 3"),
+            Hotkey::Numpad4 => write!(f, "Numpad 4"),
+            Hotkey::Numpad5 => write!(f, "Numpad 5"),
+            Hotkey::Numpad6 => write!(f, "Numpad 6"),
+            Hotkey::Numpad7 => write!(f, "Numpad 7"),
+            Hotkey::Numpad8 => write!(f, "Numpad 8"),
+            Hotkey::Numpad9 => write!(f, "Numpad 9"),
+            Hotkey::MouseMiddle => write!(f, "Mouse Middle"),
+            Hotkey::MouseBack => write!(f, "Mouse Back"),
+            Hotkey::MouseForward => write!(f, "Mouse Forward"),
+            Hotkey::Custom(trigger) => write!(f, "{}", trigger),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+// --------------------------------------------------------
+
+// ------------------- Mouse Button Enum ------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+enum MouseButtonChoice {
+    #[default]
+    Left,
+    Right,
+    Middle,
+}
+
+impl MouseButtonChoice {
+    const ALL: [MouseButtonChoice; 3] = [
+        MouseButtonChoice::Left,
+        MouseButtonChoice::Right,
+        MouseButtonChoice::Middle,
+    ];
+
+    fn to_enigo_button(self) -> MouseButton {
+        match self {
+            MouseButtonChoice::Left => MouseButton::Left,
+            MouseButtonChoice::Right => MouseButton::Right,
+            MouseButtonChoice::Middle => MouseButton::Middle,
+        }
+    }
+
+    fn to_rdev_button(self) -> Button {
+        match self {
+            MouseButtonChoice::Left => Button::Left,
+            MouseButtonChoice::Right => Button::Right,
+            MouseButtonChoice::Middle => Button::Middle,
+        }
+    }
+
+    fn from_letter(letter: char) -> Option<Self> {
+        match letter.to_ascii_uppercase() {
+            'L' => Some(MouseButtonChoice::Left),
+            'R' => Some(MouseButtonChoice::Right),
+            'M' => Some(MouseButtonChoice::Middle),
+            _ => None,
+        }
+    }
+
+    fn letter(self) -> char {
+        match self {
+            MouseButtonChoice::Left => 'L',
+            MouseButtonChoice::Right => 'R',
+            MouseButtonChoice::Middle => 'M',
+        }
+    }
+}
+
+// "LLR" clicks left, left, right and then repeats. Whitespace is ignored; on
+// failure the offending character is returned.
+fn parse_click_pattern(input: &str) -> Result<Vec<MouseButtonChoice>, char> {
+    input
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| MouseButtonChoice::from_letter(c).ok_or(c))
+        .collect()
+}
+
+fn format_click_pattern(pattern: &[MouseButtonChoice]) -> String {
+    pattern.iter().map(|button| button.letter()).collect()
+}
+
+impl std::fmt::Display for MouseButtonChoice {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}", self)
     }
 }
+// --------------------------------------------------------
+
+// ------------------- Press Key Enum ---------------------
+
+// Keys the keyboard auto-presser can send. Function, navigation and numpad keys
+// are left out on purpose since they can be bound as hotkeys, and the presser
+// would end up toggling itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+enum PressKey {
+    #[default]
+    Space,
+    Enter,
+    Tab,
+    Backspace,
+    Up,
+    Down,
+    Left,
+    Right,
+    Char(char),
+}
+
+impl PressKey {
+    const ALL: [PressKey; 44] = [
+        PressKey::Space,
+        PressKey::Enter,
+        PressKey::Tab,
+        PressKey::Backspace,
+        PressKey::Up,
+        PressKey::Down,
+        PressKey::Left,
+        PressKey::Right,
+        PressKey::Char('a'),
+        PressKey::Char('b'),
+        PressKey::Char('c'),
+        PressKey::Char('d'),
+        PressKey::Char('e'),
+        PressKey::Char('f'),
+        PressKey::Char('g'),
+        PressKey::Char('h'),
+        PressKey::Char('i'),
+        PressKey::Char('j'),
+        PressKey::Char('k'),
+        PressKey::Char('l'),
+        PressKey::Char('m'),
+        PressKey::Char('n'),
+        PressKey::Char('o'),
+        PressKey::Char('p'),
+        PressKey::Char('q'),
+        PressKey::Char('r'),
+        PressKey::Char('s'),
+        PressKey::Char('t'),
+        PressKey::Char('u'),
+        PressKey::Char('v'),
+        PressKey::Char('w'),
+        PressKey::Char('x'),
+        PressKey::Char('y'),
+        PressKey::Char('z'),
+        PressKey::Char('0'),
+        PressKey::Char('1'),
+        PressKey::Char('2'),
+        PressKey::Char('3'),
+        PressKey::Char('4'),
+        PressKey::Char('5'),
+        PressKey::Char('6'),
+        PressKey::Char('7'),
+        PressKey::Char('8'),
+        PressKey::Char('9'),
+    ];
+
+    fn to_enigo_key(self) -> enigo::Key {
+        match self {
+            PressKey::Space => enigo::Key::Space,
+            PressKey::Enter => enigo::Key::Return,
+            PressKey::Tab => enigo::Key::Tab,
+            PressKey::Backspace => enigo::Key::Backspace,
+            PressKey::Up => enigo::Key::UpArrow,
+            PressKey::Down => enigo::Key::DownArrow,
+            PressKey::Left => enigo::Key::LeftArrow,
+            PressKey::Right => enigo::Key::RightArrow,
+            PressKey::Char(c) => enigo::Key::Layout(c),
+        }
+    }
+}
 
-impl Default for Hotkey {
+impl std::fmt::Display for PressKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PressKey::Char(c) => write!(f, "{}", c.to_ascii_uppercase()),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+// `count` presses of `key`, one per tick, then `finish_key` once if there is
+// one. Without `repeat` the presser stops after a single cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+struct KeySequence {
+    key: PressKey,
+    // Never 0; the GUI rejects it and the loop treats it as 1.
+    count: u32,
+    finish_key: Option<PressKey>,
+    repeat: bool,
+}
+
+// A single key pressed over and over, as before the sequence options existed.
+impl Default for KeySequence {
     fn default() -> Self {
-        Hotkey::F6
+        Self { key: PressKey::default(), count: 1, finish_key: None, repeat: true }
     }
 }
 // --------------------------------------------------------
 
+// ------------------- Scroll Direction Enum --------------
 
-const DEFAULT_DELAY_MS: u32 = 800;
-const DEFAULT_HOTKEY: Hotkey = Hotkey::F6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum ScrollDirection {
+    Up,
+    #[default]
+    Down,
+}
 
-fn main() -> iced::Result {
-    let clicking_flag = Arc::new(AtomicBool::new(false));
-    let delay_ms = Arc::new(AtomicUsize::new(DEFAULT_DELAY_MS as usize));
-    let selected_hotkey = Arc::new(Mutex::new(DEFAULT_HOTKEY));
-    let listener_handle = Arc::new(Mutex::new(None));
-
-    spawn_clicker_loop(clicking_flag.clone(), delay_ms.clone());
-
-    let hotkey_flag = clicking_flag.clone();
-    let delay_for_hotkey = delay_ms.clone();
-    let hotkey_arc = selected_hotkey.clone();
-    let listener_handle_arc = listener_handle.clone();
-
-    start_hotkey_listener(
-        hotkey_flag,
-        delay_for_hotkey,
-        hotkey_arc,
-        listener_handle_arc,
-    );
+impl ScrollDirection {
+    const ALL: [ScrollDirection; 2] = [ScrollDirection::Up, ScrollDirection::Down];
 
-    AutoClickerApp::run(Settings {
-        flags: (clicking_flag, delay_ms, selected_hotkey, listener_handle),
-        ..Default::default()
-    })
+    // enigo scrolls down for positive lengths.
+    fn signed_lines(self, lines: i32) -> i32 {
+        match self {
+            ScrollDirection::Up => -lines,
+            ScrollDirection::Down => lines,
+        }
+    }
 }
 
-fn spawn_clicker_loop(flag: Arc<AtomicBool>, delay: Arc<AtomicUsize>) {
-    thread::spawn(move || {
-        let mut enigo = Enigo::new();
-        let mut last_time = std::time::Instant::now();
-        loop {
-            if flag.load(Ordering::Relaxed) {
-                println!("Starting auto-clicker in 500ms delay...");
-                std::thread::sleep(Duration::from_millis(1000));
-
-                while flag.load(Ordering::Relaxed) {
-                    enigo.mouse_click(MouseButton::Left);
-                    println!("[AutoClicker] Clicked!");
-                    let now = std::time::Instant::now();
-                    let elapsed = now.duration_since(last_time);
-                    println!(
-                        "[AutoClicker] time since last click: {:?}",
-                        elapsed
-                    );
-                    last_time = now;
-                    let sleep_time = delay.load(Ordering::Relaxed);
-                    std::thread::sleep(Duration::from_millis(sleep_time as u64));
-                }
-                println!("CLICKER THREAD STOPPED.");
-            }
+impl std::fmt::Display for ScrollDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Scroll {:?}", self)
+    }
+}
+// --------------------------------------------------------
+
+// ------------------- Modifier Key Enum ------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum Modifier {
+    Shift,
+    Control,
+    Alt,
+}
+
+impl Modifier {
+    const ALL: [Modifier; 3] = [Modifier::Shift, Modifier::Control, Modifier::Alt];
+
+    fn to_enigo_key(self) -> enigo::Key {
+        match self {
+            Modifier::Shift => enigo::Key::Shift,
+            Modifier::Control => enigo::Key::Control,
+            Modifier::Alt => enigo::Key::Alt,
+        }
+    }
 
-            std::thread::sleep(Duration::from_millis(50));
+    // Either side counts; AltGr is taken as Alt as well.
+    fn from_key(key: Key) -> Option<Modifier> {
+        match key {
+            Key::ShiftLeft | Key::ShiftRight => Some(Modifier::Shift),
+            Key::ControlLeft | Key::ControlRight => Some(Modifier::Control),
+            Key::Alt | Key::AltGr => Some(Modifier::Alt),
+            _ => None,
         }
-    });
+    }
 }
 
-fn start_hotkey_listener(
-    flag: Arc<AtomicBool>,
-    delay: Arc<AtomicUsize>,
-    selected_hotkey: Arc<Mutex<Hotkey>>,
-    listener_handle_arc: Arc<Mutex<Option<thread::JoinHandle<()>>>>,
-) {
-    let handle = thread::spawn(move || {
-        println!("Hotkey listener started.");
-        let hotkey = *selected_hotkey.lock().unwrap();
+// Kept in `Modifier::ALL` order, so the same set always looks the same.
+fn set_modifier(modifiers: &mut Vec<Modifier>, modifier: Modifier, enabled: bool) {
+    modifiers.retain(|held| *held != modifier);
+    if enabled {
+        modifiers.push(modifier);
+        modifiers.sort_by_key(|held| Modifier::ALL.iter().position(|m| m == held));
+    }
+}
 
-        if let Err(e) = listen(move |event| {
-            if let EventType::KeyPress(key) = event.event_type {
-                if key == hotkey.to_rdev_key() {
-                    toggle_clicker(flag.clone());
-                }
-            }
-        }) {
-            println!("Error listening to keyboard events: {:?}", e);
+// E.g. "Ctrl+Alt+F6".
+fn format_combo(modifiers: &[Modifier], hotkey: Hotkey) -> String {
+    modifiers
+        .iter()
+        .map(|modifier| modifier.to_string())
+        .chain(std::iter::once(hotkey.to_string()))
+        .collect::<Vec<_>>()
+        .join("+")
+}
+
+impl std::fmt::Display for Modifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Modifier::Shift => write!(f, "Shift"),
+            Modifier::Control => write!(f, "Ctrl"),
+            Modifier::Alt => write!(f, "Alt"),
         }
-    });
+    }
+}
+// --------------------------------------------------------
 
-    let mut lock = listener_handle_arc.lock().unwrap();
-    if let Some(old_handle) = lock.take() {
-        println!("Shutting down previous listener...");
-        // Note: rdev's listener is blocking; there's no clean way to kill it except process kill
-        // so we simply replace the thread handle and let the old one die if possible.
-        // In a real production app you'd architect this differently!
-        drop(old_handle);
+// ------------------- Click Type Enum --------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+enum ClickType {
+    #[default]
+    Single,
+    Double,
+    Triple,
+}
+
+impl ClickType {
+    const ALL: [ClickType; 3] = [ClickType::Single, ClickType::Double, ClickType::Triple];
+
+    fn click_count(self) -> u32 {
+        match self {
+            ClickType::Single => 1,
+            ClickType::Double => 2,
+            ClickType::Triple => 3,
+        }
     }
-    *lock = Some(handle);
 }
 
-fn toggle_clicker(flag: Arc<AtomicBool>) {
-    let current = flag.load(Ordering::Relaxed);
-    if current {
-        stop_clicker(flag);
-    } else {
-        start_clicker(flag);
+impl std::fmt::Display for ClickType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} click", self)
     }
 }
+// --------------------------------------------------------
 
-fn start_clicker(flag: Arc<AtomicBool>) {
-    if !flag.load(Ordering::Relaxed) {
-        flag.store(true, Ordering::Relaxed);
-        println!("Clicker STARTED.");
+// ------------------- Ramp Shape Enum --------------------
+
+// How the ramp moves between its two delays: once across, or back and forth
+// every ramp duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum RampShape {
+    #[default]
+    Linear,
+    Alternating,
+}
+
+impl RampShape {
+    const ALL: [RampShape; 2] = [RampShape::Linear, RampShape::Alternating];
+}
+
+impl std::fmt::Display for RampShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
     }
 }
+// --------------------------------------------------------
 
-fn stop_clicker(flag: Arc<AtomicBool>) {
-    if flag.load(Ordering::Relaxed) {
-        flag.store(false, Ordering::Relaxed);
-        println!("Clicker STOPPED.");
+// ------------------- Click Mode Enum --------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+enum ClickMode {
+    #[default]
+    Toggle,
+    Hold,
+}
+
+impl ClickMode {
+    const ALL: [ClickMode; 2] = [ClickMode::Toggle, ClickMode::Hold];
+}
+
+impl std::fmt::Display for ClickMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
     }
 }
+// --------------------------------------------------------
 
-#[derive(Debug, Clone)]
-enum Message {
-    StartClicker,
-    StopClicker,
-    SliderChanged(u32),
-    HotkeyChanged(Hotkey),
+// ------------------- Speed Mode Enum --------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum SpeedMode {
+    #[default]
+    Milliseconds,
+    ClicksPerSecond,
 }
 
-struct AutoClickerApp {
-    is_clicking: Arc<AtomicBool>,
-    delay_ms: Arc<AtomicUsize>,
-    slider_value: u32,
-    is_toggling: bool,
-    last_toggle: std::time::Instant,
-    selected_hotkey: Arc<Mutex<Hotkey>>,
-    listener_handle: Arc<Mutex<Option<thread::JoinHandle<()>>>>,
+impl SpeedMode {
+    const ALL: [SpeedMode; 2] = [SpeedMode::Milliseconds, SpeedMode::ClicksPerSecond];
 }
 
-impl Application for AutoClickerApp {
-    type Executor = executor::Default;
-    type Message = Message;
-    type Theme = theme::Theme;
-    type Flags = (
-        Arc<AtomicBool>,
-        Arc<AtomicUsize>,
-        Arc<Mutex<Hotkey>>,
-        Arc<Mutex<Option<thread::JoinHandle<()>>>>,
-    );
+impl std::fmt::Display for SpeedMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpeedMode::Milliseconds => write!(f, "ms"),
+            SpeedMode::ClicksPerSecond => write!(f, "CPS"),
+        }
+    }
+}
+// --------------------------------------------------------
 
-    fn new(flags: Self::Flags) -> (Self, Command<Self::Message>) {
-        (
-            Self {
-                is_clicking: flags.0,
-                delay_ms: flags.1,
-                slider_value: DEFAULT_DELAY_MS,
-                is_toggling: true,
-                last_toggle: std::time::Instant::now() - std::time::Duration::from_secs(1),
-                selected_hotkey: flags.2,
-                listener_handle: flags.3,
-            },
-            Command::none(),
-        )
+// ------------------- Delay Range Enum -------------------
+
+// How far the delay slider reaches. The coarser ranges step further so the
+// slider stays usable; any delay can still be typed into the text box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+enum DelayRange {
+    #[default]
+    OneSecond,
+    TenSeconds,
+    OneMinute,
+}
+
+impl DelayRange {
+    const ALL: [DelayRange; 3] =
+        [DelayRange::OneSecond, DelayRange::TenSeconds, DelayRange::OneMinute];
+
+    fn step_ms(self) -> u32 {
+        match self {
+            DelayRange::OneSecond => 10,
+            DelayRange::TenSeconds => 100,
+            DelayRange::OneMinute => 1000,
+        }
     }
 
-    fn title(&self) -> String {
-        String::from("Rust Auto Clicker")
+    // Starts on a step so every slider position is a round number.
+    fn min_ms(self) -> u32 {
+        self.step_ms().max(MIN_DELAY_MS)
     }
 
-    fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
-        println!("Received message: {:?}", message);
+    fn max_ms(self) -> u32 {
+        match self {
+            DelayRange::OneSecond => 1000,
+            DelayRange::TenSeconds => 10_000,
+            DelayRange::OneMinute => 60_000,
+        }
+    }
+}
 
-        match message {
-            Message::StartClicker => {
-                if !self.is_clicking.load(Ordering::Relaxed) {
-                    self.is_clicking.store(true, Ordering::Relaxed);
-                    println!("Clicker STARTED.");
-                }
-            }
-            Message::StopClicker => {
-                if self.is_clicking.load(Ordering::Relaxed) {
-                    self.is_clicking.store(false, Ordering::Relaxed);
-                    println!("Clicker STOPPED.");
-                }
-            }
-            Message::SliderChanged(value) => {
-                self.slider_value = value;
-                self.delay_ms.store(value as usize, Ordering::Relaxed);
-                println!("Delay updated to {} ms", value);
-            }
-            Message::HotkeyChanged(hotkey) => {
-                {
-                    let mut lock = self.selected_hotkey.lock().unwrap();
-                    *lock = hotkey;
-                }
-                println!("Hotkey changed to {:?}", hotkey);
+impl std::fmt::Display for DelayRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "up to {} s", self.max_ms() / 1000)
+    }
+}
+// --------------------------------------------------------
 
-                start_hotkey_listener(
-                    self.is_clicking.clone(),
-                    self.delay_ms.clone(),
-                    self.selected_hotkey.clone(),
-                    self.listener_handle.clone(),
-                );
-            }
+// ------------------- Stop Conditions --------------------
+
+// Every limit that can end a run on its own. They all apply at once; `first`
+// picks whichever is due soonest, which is what the loop checks and the GUI
+// shows. A limit that has been reached is always due, so it always wins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StopCondition {
+    None,
+    // `eta` is estimated from the configured delay.
+    MaxClicks { remaining: usize, eta: Instant },
+    MaxDuration(Instant),
+    AtTime(Instant),
+}
+
+impl StopCondition {
+    fn first(
+        max_clicks: usize,
+        clicks_done: usize,
+        delay_ms: usize,
+        run_deadline: Option<Instant>,
+        stop_at: Option<Instant>,
+        now: Instant,
+    ) -> StopCondition {
+        let mut candidates = Vec::new();
+        if max_clicks > 0 {
+            let remaining = max_clicks.saturating_sub(clicks_done);
+            let eta_ms = (remaining as u64).saturating_mul(delay_ms as u64);
+            let eta = now + Duration::from_millis(eta_ms).min(MAX_STOP_ETA);
+            candidates.push(StopCondition::MaxClicks { remaining, eta });
         }
+        candidates.extend(run_deadline.map(StopCondition::MaxDuration));
+        candidates.extend(stop_at.map(StopCondition::AtTime));
+        candidates
+            .into_iter()
+            .min_by_key(|condition| condition.due())
+            .unwrap_or(StopCondition::None)
+    }
 
-        Command::none()
+    fn due(self) -> Option<Instant> {
+        match self {
+            StopCondition::None => None,
+            StopCondition::MaxClicks { eta, .. } => Some(eta),
+            StopCondition::MaxDuration(at) | StopCondition::AtTime(at) => Some(at),
+        }
     }
 
-    fn view(&self) -> Element<Self::Message> {
-        let label = if self.is_clicking.load(Ordering::Relaxed) {
-            "Auto Clicker is RUNNING"
-        } else {
-            "Auto Clicker is STOPPED"
-        };
+    fn reached(self, now: Instant) -> bool {
+        match self {
+            StopCondition::None => false,
+            StopCondition::MaxClicks { remaining, .. } => remaining == 0,
+            StopCondition::MaxDuration(at) | StopCondition::AtTime(at) => now >= at,
+        }
+    }
+}
+// --------------------------------------------------------
 
-        let start_button = if self.is_clicking.load(Ordering::Relaxed) {
-            button("Start")
-        } else {
-            button("Start").on_press(Message::StartClicker)
-        };
+// ------------------- Sequence Points --------------------
 
-        let stop_button = if self.is_clicking.load(Ordering::Relaxed) {
-            button("Stop").on_press(Message::StopClicker)
-        } else {
-            button("Stop")
-        };
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "StoredSequencePoint")]
+struct SequencePoint {
+    pos: (i32, i32),
+    // Clicks on this point before moving on; 0 is treated as 1.
+    clicks: u32,
+    // Extra wait after the last click on this point.
+    dwell_ms: u32,
+}
 
-        let hotkey_picklist = PickList::new(
-            &Hotkey::ALL[..],
-            Some(*self.selected_hotkey.lock().unwrap()),
-            Message::HotkeyChanged,
-        )
-        .placeholder("Select Hotkey");
+impl SequencePoint {
+    fn new(pos: (i32, i32)) -> Self {
+        Self { pos, clicks: 1, dwell_ms: 0 }
+    }
+}
 
-        let start_stop_row = row![start_button, stop_button].spacing(20);
+// Profiles saved before points had their own settings stored bare coordinates.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredSequencePoint {
+    Coordinates((i32, i32)),
+    Full { pos: (i32, i32), clicks: u32, dwell_ms: u32 },
+}
 
-        column![
-            text(label),
-            text(format!("Delay: {} ms", self.slider_value)),
-            slider(10..=1000, self.slider_value, Message::SliderChanged).step(10u32),
-            hotkey_picklist,
-            start_stop_row,
-        ]
-        .spacing(20)
-        .padding(20)
-        .width(Length::Shrink)
-        .into()
+impl From<StoredSequencePoint> for SequencePoint {
+    fn from(stored: StoredSequencePoint) -> Self {
+        match stored {
+            StoredSequencePoint::Coordinates(pos) => SequencePoint::new(pos),
+            StoredSequencePoint::Full { pos, clicks, dwell_ms } => {
+                SequencePoint { pos, clicks, dwell_ms }
+            }
+        }
+    }
+}
+// --------------------------------------------------------
+
+// ------------------- Drag Mode --------------------------
+
+// Press at `from`, move to `to` and release, once per tick. `steps` moves in
+// between make the drag smooth; 0 jumps straight to the end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct DragMode {
+    from: Option<(i32, i32)>,
+    to: Option<(i32, i32)>,
+    steps: u32,
+}
+
+impl DragMode {
+    fn endpoints(self) -> Option<((i32, i32), (i32, i32))> {
+        self.from.zip(self.to)
+    }
+
+    // Every position after the press, ending exactly on `to`.
+    fn path(from: (i32, i32), to: (i32, i32), steps: u32) -> Vec<(i32, i32)> {
+        let moves = steps as i64 + 1;
+        (1..=moves)
+            .map(|i| {
+                let x = from.0 as i64 + (to.0 - from.0) as i64 * i / moves;
+                let y = from.1 as i64 + (to.1 - from.1) as i64 * i / moves;
+                (x as i32, y as i32)
+            })
+            .collect()
+    }
+}
+// --------------------------------------------------------
+
+// ------------------- Theme Choice Enum ------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+enum ThemeChoice {
+    #[default]
+    Light,
+    Dark,
+}
+
+impl ThemeChoice {
+    const ALL: [ThemeChoice; 2] = [ThemeChoice::Light, ThemeChoice::Dark];
+
+    fn to_theme(self) -> theme::Theme {
+        match self {
+            ThemeChoice::Light => theme::Theme::Light,
+            ThemeChoice::Dark => theme::Theme::Dark,
+        }
+    }
+}
+
+impl std::fmt::Display for ThemeChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} theme", self)
+    }
+}
+// --------------------------------------------------------
+
+
+const DEFAULT_DELAY_MS: u32 = 800;
+// Anything faster floods the OS input queue and can make the whole machine
+// unresponsive, so every delay written to `delay_ms` is clamped to this.
+const MIN_DELAY_MS: u32 = 10;
+const DEFAULT_HOTKEY: Hotkey = Hotkey::F6;
+const DEFAULT_MOUSE_BUTTON: MouseButtonChoice = MouseButtonChoice::Left;
+const DEFAULT_CLICK_MODE: ClickMode = ClickMode::Toggle;
+const DEFAULT_START_DELAY_MS: u32 = 1000;
+// Gap between the individual clicks of a double/triple click; `delay_ms` applies
+// between whole groups.
+const MULTI_CLICK_GAP_MS: u64 = 30;
+const DEFAULT_JIGGLE_INTERVAL_MS: u32 = 5000;
+const MAX_JIGGLE_INTERVAL_MS: u32 = 60_000;
+const JIGGLE_DISTANCE_PX: i32 = 5;
+const JIGGLE_PAUSE_MS: u64 = 50;
+const MAX_START_DELAY_MS: u32 = 3000;
+const MAX_CLICK_RADIUS_PX: u32 = 50;
+const MAX_HOLD_MS: u32 = 2000;
+const MAX_RUN_MINUTES: u32 = 240;
+// Click-based stop estimates are capped here; a huge max clicks at a long delay
+// would otherwise overflow, and a year out never wins against another stop.
+const MAX_STOP_ETA: Duration = Duration::from_secs(365 * 24 * 60 * 60);
+// Gives a freshly activated window time to take focus before it's clicked.
+const FOCUS_SETTLE_MS: u64 = 50;
+// Consecutive failed cursor moves before the clicker gives up; each retry waits
+// this many milliseconds longer than the last.
+const MAX_INPUT_FAILURES: u32 = 5;
+const INPUT_RETRY_BACKOFF_MS: u64 = 100;
+const DEFAULT_SCROLL_LINES: u32 = 3;
+const MAX_SCROLL_LINES: u32 = 20;
+const DEFAULT_DRAG_STEPS: u32 = 10;
+const MAX_DRAG_STEPS: u32 = 100;
+// Pause after the press and after each move, so the target sees a real drag.
+const DRAG_STEP_MS: u64 = 10;
+const DEFAULT_RAMP_START_MS: u32 = 500;
+const DEFAULT_RAMP_END_MS: u32 = 50;
+const DEFAULT_RAMP_DURATION_MS: u32 = 10_000;
+const MAX_RAMP_DURATION_MS: u32 = 120_000;
+// Upper end of both ramp delays, which alternating mode uses as its two rates.
+const MAX_RAMP_DELAY_MS: u32 = 5000;
+const DEFAULT_BURST_SIZE: u32 = 10;
+const MAX_BURST_SIZE: u32 = 100;
+const DEFAULT_BURST_PAUSE_MS: u32 = 3000;
+const MAX_BURST_PAUSE_MS: u32 = 30_000;
+const MIN_CPS: u32 = 1;
+const MAX_CPS: u32 = 50;
+const MAX_JITTER_MS: u32 = 500;
+const DEFAULT_HUMANIZE_STD_DEV_MS: u32 = 50;
+const DEFAULT_RANGE_MIN_MS: u32 = 200;
+const DEFAULT_RANGE_MAX_MS: u32 = 600;
+const MAX_RANGE_DELAY_MS: u32 = 5000;
+const MAX_HUMANIZE_STD_DEV_MS: u32 = 300;
+// Number of recent click intervals averaged into the measured CPS readout.
+const CPS_WINDOW: usize = 10;
+const TICK_INTERVAL_MS: u64 = 250;
+// How often a headless run checks whether it should exit.
+const HEADLESS_POLL_MS: u64 = 100;
+// Time to move the mouse off the Test click button before the click is sent.
+const TEST_CLICK_DELAY_MS: u64 = 2000;
+// A test click onto our own button comes back as another press; until this has
+// passed the test still counts as pending, so that press is ignored.
+const TEST_CLICK_SETTLE_MS: u64 = 300;
+// Always stops the clicker, whatever the mode. Deliberately not one of the
+// selectable hotkeys so the two can never be confused.
+const PANIC_KEY: Key = Key::Escape;
+// With the corner failsafe on, the mouse coming within this many pixels of the
+// desktop's top-left corner stops the clicker. Our own moves count too, so corner
+// targets need it off.
+const FAILSAFE_CORNER_PX: f64 = 3.0;
+// Toggle-mode hotkey presses closer together than this are treated as one.
+const HOTKEY_DEBOUNCE_MS: u64 = 250;
+// Slower tick used while stopped. The hotkey thread and the tray can still start
+// the clicker at any time, and the GUI has to notice without user interaction.
+const IDLE_TICK_INTERVAL_MS: u64 = 500;
+// Idle tick in low power mode. The worker threads block on the condvar while
+// stopped, so this is nearly all the app wakes for; the price is that a start
+// from the hotkey or tray can take this long to show up in the window.
+const LOW_POWER_IDLE_TICK_INTERVAL_MS: u64 = 2000;
+// Settings are written this long after the last change, so dragging a slider or
+// typing a delay writes config.json once rather than on every step.
+const CONFIG_SAVE_DEBOUNCE_MS: u64 = 500;
+// Starting from the Start button below this delay asks for a second click first.
+// The hotkey, tray and schedule skip the check: they are set up deliberately,
+// and Esc stops the clicker no matter how fast it runs.
+const CONFIRM_BELOW_DELAY_MS: u32 = 20;
+// Cursor moves closer together than this are dropped while recording a path.
+const PATH_SAMPLE_INTERVAL_MS: u64 = 20;
+// Default size for the first launch; the content scrolls when it doesn't fit.
+const WINDOW_WIDTH: f32 = 1100.0;
+const WINDOW_HEIGHT: f32 = 900.0;
+const WARNING_COLOR: Color = Color::from_rgb(0.85, 0.2, 0.2);
+const CLICK_FLASH_COLOR: Color = Color::from_rgb(0.2, 0.8, 0.3);
+const CLICK_IDLE_COLOR: Color = Color::from_rgb(0.55, 0.55, 0.55);
+
+fn window_level(always_on_top: bool) -> iced::window::Level {
+    if always_on_top {
+        iced::window::Level::AlwaysOnTop
+    } else {
+        iced::window::Level::Normal
+    }
+}
+
+#[derive(Debug, Clone)]
+enum TestClickStatus {
+    Pending,
+    Sent(MouseButtonChoice, (i32, i32)),
+    Failed(String),
+}
+
+// One click with the current button, target or offset and modifiers, on its own
+// thread so the GUI doesn't stall during the delay.
+fn spawn_test_click(
+    button: MouseButtonChoice,
+    target: Option<(i32, i32)>,
+    offset: Option<(i32, i32)>,
+    modifiers: Vec<Modifier>,
+    status: Arc<Mutex<Option<TestClickStatus>>>,
+) {
+    *status.lock().unwrap() = Some(TestClickStatus::Pending);
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(TEST_CLICK_DELAY_MS));
+        let result = create_enigo().and_then(|mut enigo| {
+            let cursor = enigo.mouse_location();
+            let position = match (target, offset) {
+                (Some(position), _) => position,
+                (None, Some((dx, dy))) => (cursor.0 + dx, cursor.1 + dy),
+                (None, None) => cursor,
+            };
+            if position != cursor {
+                move_to_global(&mut enigo, position.0, position.1)?;
+            }
+            for modifier in &modifiers {
+                enigo.key_down(modifier.to_enigo_key());
+            }
+            enigo.mouse_click(button.to_enigo_button());
+            for modifier in modifiers.iter().rev() {
+                enigo.key_up(modifier.to_enigo_key());
+            }
+            if target.is_none() && position != cursor {
+                let _ = move_to_global(&mut enigo, cursor.0, cursor.1);
+            }
+            Ok(position)
+        });
+        thread::sleep(Duration::from_millis(TEST_CLICK_SETTLE_MS));
+        let result = match result {
+            Ok(position) => {
+                println!("Test click sent with {} at {:?}", button, position);
+                TestClickStatus::Sent(button, position)
+            }
+            Err(e) => {
+                println!("Test click failed: {}", e);
+                TestClickStatus::Failed(e)
+            }
+        };
+        *status.lock().unwrap() = Some(result);
+    });
+}
+
+fn format_delay(delay_ms: u32) -> String {
+    if delay_ms >= 1000 {
+        format!("{:.1} s", delay_ms as f64 / 1000.0)
+    } else {
+        format!("{} ms", delay_ms)
+    }
+}
+
+// OS and architecture, plus the session type on Linux, since rdev and enigo only
+// work under X11 there.
+fn platform_description() -> String {
+    let platform = format!("{} {}", std::env::consts::OS, std::env::consts::ARCH);
+    match std::env::var("XDG_SESSION_TYPE") {
+        Ok(session) if cfg!(target_os = "linux") && !session.is_empty() => {
+            format!("{} ({})", platform, session)
+        }
+        _ => platform,
+    }
+}
+
+fn format_countdown(remaining: Duration) -> String {
+    let secs = remaining.as_secs();
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+fn clamp_delay_ms(delay_ms: u32) -> u32 {
+    delay_ms.max(MIN_DELAY_MS)
+}
+
+fn cps_to_delay_ms(cps: u32) -> u32 {
+    clamp_delay_ms(1000 / cps.clamp(MIN_CPS, MAX_CPS))
+}
+
+// Time from the first press to the last release: every hold, plus the gaps
+// between the clicks of a double or triple click.
+fn press_duration_ms(hold_ms: u64, click_count: u32) -> u64 {
+    let click_count = click_count.max(1) as u64;
+    hold_ms * click_count + MULTI_CLICK_GAP_MS * (click_count - 1)
+}
+
+// Clicks are paced on a grid: each one is due a period after the previous one
+// was due, not a period after it finished, so the time the click itself takes
+// doesn't pile up. More than a whole period behind, e.g. after a slow move, the
+// grid restarts from now rather than firing a burst of catch-up clicks.
+fn next_click_due(previous: Option<Instant>, period: Duration, now: Instant) -> Instant {
+    previous
+        .map(|previous| previous + period)
+        .filter(|&due| due + period >= now)
+        .unwrap_or(now + period)
+}
+
+// Linear from `start_ms` to `end_ms` over `duration_ms`, then holds at `end_ms`.
+fn ramped_delay_ms(start_ms: usize, end_ms: usize, duration_ms: usize, elapsed: Duration) -> usize {
+    let progress = if duration_ms == 0 {
+        1.0
+    } else {
+        (elapsed.as_millis() as f64 / duration_ms as f64).min(1.0)
+    };
+    let delay = start_ms as f64 + (end_ms as f64 - start_ms as f64) * progress;
+    clamp_delay_ms(delay.round() as u32) as usize
+}
+
+// Starts on `start_ms` and swaps to the other delay every `duration_ms`.
+fn alternating_delay_ms(
+    start_ms: usize,
+    end_ms: usize,
+    duration_ms: usize,
+    elapsed: Duration,
+) -> usize {
+    let phase = if duration_ms == 0 { 0 } else { elapsed.as_millis() / duration_ms as u128 };
+    let delay = if phase % 2 == 0 { start_ms } else { end_ms };
+    clamp_delay_ms(delay as u32) as usize
+}
+
+fn delay_ms_to_cps(delay_ms: u32) -> u32 {
+    (1000 / clamp_delay_ms(delay_ms)).clamp(MIN_CPS, MAX_CPS)
+}
+
+// Measured rate in hundredths of a click per second, so it fits in an AtomicUsize.
+fn measured_cps_hundredths(intervals: &VecDeque<Duration>) -> usize {
+    let total: Duration = intervals.iter().sum();
+    if intervals.is_empty() || total.is_zero() {
+        return 0;
+    }
+    (intervals.len() as f64 * 100.0 / total.as_secs_f64()).round() as usize
+}
+
+fn average_interval_us(intervals: &VecDeque<Duration>) -> usize {
+    if intervals.is_empty() {
+        return 0;
+    }
+    let total: Duration = intervals.iter().sum();
+    (total.as_micros() / intervals.len() as u128) as usize
+}
+
+// Standard deviation of the intervals: how unevenly the clicks are spaced.
+fn interval_std_dev_us(intervals: &VecDeque<Duration>) -> usize {
+    if intervals.len() < 2 {
+        return 0;
+    }
+    let mean = average_interval_us(intervals) as f64;
+    let variance = intervals
+        .iter()
+        .map(|interval| (interval.as_micros() as f64 - mean).powi(2))
+        .sum::<f64>()
+        / intervals.len() as f64;
+    variance.sqrt().round() as usize
+}
+
+// Numeric text inputs drop anything that isn't a digit; empty reads as 0.
+fn digits_value(value: &str) -> u32 {
+    let digits: String = value.chars().filter(char::is_ascii_digit).collect();
+    if digits.is_empty() {
+        return 0;
+    }
+    // Only digits are left, so parsing can only fail on overflow. Saturate rather
+    // than fall back to 0, which the max clicks field reads as "unlimited".
+    digits.parse().unwrap_or(u32::MAX)
+}
+
+fn jittered_delay_ms(delay_ms: usize, jitter_ms: usize, rng: &mut impl Rng) -> u64 {
+    if jitter_ms == 0 {
+        return delay_ms as u64;
+    }
+    let jitter = jitter_ms as i64;
+    let offset = rng.gen_range(-jitter..=jitter);
+    // Jitter must not undercut the safety floor either.
+    (delay_ms as i64 + offset).max(MIN_DELAY_MS as i64) as u64
+}
+
+// `origin` is the top-left of the whole desktop; rdev reports positions in the
+// same virtual-desktop coordinates.
+fn in_failsafe_corner(x: f64, y: f64, origin: (i32, i32)) -> bool {
+    x - origin.0 as f64 <= FAILSAFE_CORNER_PX && y - origin.1 as f64 <= FAILSAFE_CORNER_PX
+}
+
+// enigo 0.1 can't report a failed connection itself: on Linux it needs an X11
+// display (XWayland is fine) and otherwise does nothing or panics on first use.
+fn create_enigo() -> Result<Enigo, String> {
+    create_backend(std::env::var_os("DISPLAY"), Enigo::new)
+}
+
+// The display and constructor are passed in so both failure paths can be tested
+// without a real input backend.
+fn create_backend<T>(
+    display: Option<std::ffi::OsString>,
+    new: impl FnOnce() -> T + std::panic::UnwindSafe,
+) -> Result<T, String> {
+    if cfg!(target_os = "linux") && display.is_none() {
+        return Err(String::from(
+            "No X11 display found (DISPLAY is not set). Clicking needs X11 or XWayland.",
+        ));
+    }
+    std::panic::catch_unwind(new)
+        .map_err(|_| String::from("Failed to initialise the mouse/keyboard input backend."))
+}
+
+// Targets are global virtual-desktop coordinates, as captured by device_query,
+// which go negative for a monitor left of or above the primary one. Not every
+// platform maps absolute moves onto those consistently, so check where the
+// cursor actually landed and correct with a relative move.
+// enigo 0.1 reports no errors of its own, so a cursor that still isn't where it
+// was sent after the correction is the only sign that the input backend failed.
+fn move_to_global(enigo: &mut Enigo, x: i32, y: i32) -> Result<(), String> {
+    enigo.mouse_move_to(x, y);
+    let (actual_x, actual_y) = enigo.mouse_location();
+    if (actual_x, actual_y) == (x, y) {
+        return Ok(());
+    }
+    log::debug!(
+        "Cursor landed at ({}, {}) instead of ({}, {}), correcting.",
+        actual_x, actual_y, x, y
+    );
+    enigo.mouse_move_relative(x - actual_x, y - actual_y);
+    let (actual_x, actual_y) = enigo.mouse_location();
+    if (actual_x, actual_y) == (x, y) {
+        Ok(())
+    } else {
+        Err(format!(
+            "mouse_move_to({}, {}) left the cursor at ({}, {})",
+            x, y, actual_x, actual_y
+        ))
+    }
+}
+
+// The listener fails up front, so this is the message the GUI shows for as long
+// as the app runs. Denied permissions are called out separately since they're
+// the common case and the user can fix them.
+fn describe_listen_error(error: &rdev::ListenError) -> String {
+    match error {
+        rdev::ListenError::EventTapError => String::from(
+            "Keyboard access denied — grant Accessibility permission in System Settings > \
+             Privacy & Security, then restart the app. Hotkeys won't work until then.",
+        ),
+        rdev::ListenError::MissingDisplayError => {
+            String::from("No X11 display found, so global hotkeys are unavailable.")
+        }
+        rdev::ListenError::XRecordExtensionError => String::from(
+            "The X server has no RECORD extension, which global hotkeys need.",
+        ),
+        rdev::ListenError::KeyHookError(code) | rdev::ListenError::MouseHookError(code) => {
+            format!("Could not install the input hook (error {}), hotkeys won't work.", code)
+        }
+        other => format!("Global hotkeys are unavailable ({:?}).", other),
+    }
+}
+
+// Case-insensitive substring match against the focused window's title. If the
+// active window can't be queried the click is skipped, since clicking into an
+// unknown window is exactly what the filter is meant to prevent.
+fn active_window_matches(filter_lowercase: &str) -> bool {
+    match active_win_pos_rs::get_active_window() {
+        Ok(window) => window.title.to_lowercase().contains(filter_lowercase),
+        Err(()) => false,
+    }
+}
+
+// Normally distributed around `delay_ms`, which looks far less mechanical than
+// uniform jitter.
+fn humanized_delay_ms(delay_ms: usize, std_dev_ms: usize, rng: &mut impl Rng) -> u64 {
+    let Ok(normal) = Normal::new(delay_ms as f64, std_dev_ms as f64) else {
+        return delay_ms as u64;
+    };
+    normal.sample(rng).round().max(MIN_DELAY_MS as f64) as u64
+}
+
+// Uniform over the disc: taking the square root keeps points from bunching up
+// near the centre.
+fn random_offset_in_circle(radius: u32, rng: &mut impl Rng) -> (i32, i32) {
+    if radius == 0 {
+        return (0, 0);
+    }
+    let angle = rng.gen_range(0.0..std::f64::consts::TAU);
+    let distance = radius as f64 * rng.gen_range(0.0..=1.0f64).sqrt();
+    (
+        (distance * angle.cos()).round() as i32,
+        (distance * angle.sin()).round() as i32,
+    )
+}
+
+// ------------------- Clicker Flag -----------------------
+
+// Whether the clicker should be running. The condvar lets the idle clicker thread
+// block until it's started instead of polling, and lets it cut a sleep short when
+// it's stopped. A paused clicker is stopped, but keeps its session (the max-click
+// counter) for when it's started again.
+#[derive(Default)]
+struct ClickerFlag {
+    running: Mutex<bool>,
+    paused: AtomicBool,
+    changed: Condvar,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ClickerState {
+    Stopped,
+    Running,
+    Paused,
+}
+
+impl ClickerFlag {
+    fn is_running(&self) -> bool {
+        *self.running.lock().unwrap()
+    }
+
+    fn is_paused(&self) -> bool {
+        let running = self.running.lock().unwrap();
+        !*running && self.paused.load(Ordering::Relaxed)
+    }
+
+    fn state(&self) -> ClickerState {
+        let running = self.running.lock().unwrap();
+        if *running {
+            ClickerState::Running
+        } else if self.paused.load(Ordering::Relaxed) {
+            ClickerState::Paused
+        } else {
+            ClickerState::Stopped
+        }
+    }
+
+    // Returns whether the state actually changed. Stopping also ends a pause.
+    fn set_running(&self, running: bool) -> bool {
+        let mut current = self.running.lock().unwrap();
+        let was_paused = !running && self.paused.swap(false, Ordering::Relaxed);
+        if *current == running {
+            return was_paused;
+        }
+        *current = running;
+        self.changed.notify_all();
+        true
+    }
+
+    // Returns whether the clicker was running and is now paused.
+    fn pause(&self) -> bool {
+        let mut running = self.running.lock().unwrap();
+        if !*running {
+            return false;
+        }
+        self.paused.store(true, Ordering::Relaxed);
+        *running = false;
+        self.changed.notify_all();
+        true
+    }
+
+    // Called by the clicker thread when it starts a session: true if this start
+    // resumes a pause rather than beginning afresh.
+    fn take_resumed(&self) -> bool {
+        self.paused.swap(false, Ordering::Relaxed)
+    }
+
+    // Returns false instead once `should_exit` is set; call `wake` after setting it.
+    fn wait_until_running(&self, should_exit: &AtomicBool) -> bool {
+        let mut running = self.running.lock().unwrap();
+        while !*running {
+            if should_exit.load(Ordering::Relaxed) {
+                return false;
+            }
+            running = self.changed.wait(running).unwrap();
+        }
+        true
+    }
+
+    fn wake(&self) {
+        let _running = self.running.lock().unwrap();
+        self.changed.notify_all();
+    }
+
+    // Sleeps for `duration` or until the clicker is stopped, whichever is first.
+    fn sleep_while_running(&self, duration: Duration) {
+        let running = self.running.lock().unwrap();
+        let _ = self
+            .changed
+            .wait_timeout_while(running, duration, |running| *running)
+            .unwrap();
+    }
+}
+// --------------------------------------------------------
+
+fn main() -> iced::Result {
+    let verbose_logging = logging::init();
+    let args = cli::parse();
+    let mut config = Config::load();
+    let cli_overrides = cli::CliOverrides::apply(&args, &mut config);
+    sound::set_enabled(config.sounds_enabled);
+
+    let flags = AppFlags {
+        is_clicking: Arc::new(ClickerFlag::default()),
+        delay_ms: Arc::new(AtomicUsize::new(clamp_delay_ms(config.delay_ms) as usize)),
+        jitter_ms: Arc::new(AtomicUsize::new(0)),
+        selected_hotkey: Arc::new(Mutex::new(config.hotkey)),
+        // Defaults to the same key as the start hotkey, which keeps plain toggle behaviour.
+        stop_hotkey: Arc::new(Mutex::new(config.stop_hotkey)),
+        hotkey_modifiers: Arc::new(Mutex::new(config.hotkey_modifiers.clone())),
+        mouse_button: Arc::new(Mutex::new(config.mouse_button)),
+        click_mode: Arc::new(Mutex::new(config.click_mode)),
+        max_clicks: Arc::new(AtomicUsize::new(0)),
+        clicks_done: Arc::new(AtomicUsize::new(0)),
+        measured_cps: Arc::new(AtomicUsize::new(0)),
+        total_clicks: Arc::new(AtomicUsize::new(0)),
+        active_time_us: Arc::new(AtomicUsize::new(0)),
+        lifetime_clicks: Arc::new(AtomicUsize::new(config.lifetime_clicks as usize)),
+        target_position: Arc::new(Mutex::new(None)),
+        start_delay_ms: Arc::new(AtomicUsize::new(DEFAULT_START_DELAY_MS as usize)),
+        click_type: Arc::new(Mutex::new(ClickType::default())),
+        jiggle_enabled: Arc::new(AtomicBool::new(false)),
+        jiggle_interval_ms: Arc::new(AtomicUsize::new(DEFAULT_JIGGLE_INTERVAL_MS as usize)),
+        key_press_mode: Arc::new(AtomicBool::new(false)),
+        key_sequence: Arc::new(Mutex::new(KeySequence::default())),
+        burst_enabled: Arc::new(AtomicBool::new(false)),
+        burst_size: Arc::new(AtomicUsize::new(DEFAULT_BURST_SIZE as usize)),
+        burst_pause_ms: Arc::new(AtomicUsize::new(DEFAULT_BURST_PAUSE_MS as usize)),
+        click_radius: Arc::new(AtomicUsize::new(0)),
+        recorded_points: Arc::new(Mutex::new(Vec::new())),
+        start_deadline: Arc::new(Mutex::new(None)),
+        max_run_ms: Arc::new(AtomicUsize::new(config.max_run_ms as usize)),
+        run_deadline: Arc::new(Mutex::new(None)),
+        stop_at: Arc::new(Mutex::new(None)),
+        ramp_enabled: Arc::new(AtomicBool::new(false)),
+        ramp_start_ms: Arc::new(AtomicUsize::new(DEFAULT_RAMP_START_MS as usize)),
+        ramp_end_ms: Arc::new(AtomicUsize::new(DEFAULT_RAMP_END_MS as usize)),
+        ramp_duration_ms: Arc::new(AtomicUsize::new(DEFAULT_RAMP_DURATION_MS as usize)),
+        ramp_shape: Arc::new(Mutex::new(RampShape::default())),
+        ramp_delay_ms: Arc::new(AtomicUsize::new(0)),
+        humanize_enabled: Arc::new(AtomicBool::new(false)),
+        humanize_std_dev_ms: Arc::new(AtomicUsize::new(DEFAULT_HUMANIZE_STD_DEV_MS as usize)),
+        scroll_enabled: Arc::new(AtomicBool::new(false)),
+        scroll_direction: Arc::new(Mutex::new(ScrollDirection::default())),
+        scroll_lines: Arc::new(AtomicUsize::new(DEFAULT_SCROLL_LINES as usize)),
+        drag_enabled: Arc::new(AtomicBool::new(false)),
+        drag: Arc::new(Mutex::new(DragMode { steps: DEFAULT_DRAG_STEPS, ..Default::default() })),
+        target_window: Arc::new(Mutex::new(String::new())),
+        input_error: Arc::new(Mutex::new(None)),
+        click_error: Arc::new(Mutex::new(None)),
+        listener_error: Arc::new(Mutex::new(None)),
+        listener_active: Arc::new(AtomicBool::new(false)),
+        modifiers: Arc::new(Mutex::new(Vec::new())),
+        click_pattern: Arc::new(Mutex::new(Vec::new())),
+        hold_ms: Arc::new(AtomicUsize::new(0)),
+        range_enabled: Arc::new(AtomicBool::new(false)),
+        range_min_ms: Arc::new(AtomicUsize::new(DEFAULT_RANGE_MIN_MS as usize)),
+        range_max_ms: Arc::new(AtomicUsize::new(DEFAULT_RANGE_MAX_MS as usize)),
+        focus_target_window: Arc::new(AtomicBool::new(false)),
+        offset_enabled: Arc::new(AtomicBool::new(false)),
+        cursor_offset: Arc::new(Mutex::new((0, 0))),
+        preset_hotkey: Arc::new(Mutex::new(config.preset_hotkey)),
+        preset_requests: Arc::new(AtomicUsize::new(0)),
+        hotkey_capture: Arc::new(Mutex::new(None)),
+        captured_hotkey: Arc::new(Mutex::new(None)),
+        position_pick: Arc::new(AtomicBool::new(false)),
+        picked_position: Arc::new(Mutex::new(None)),
+        actual_interval_us: Arc::new(AtomicUsize::new(0)),
+        interval_jitter_us: Arc::new(AtomicUsize::new(0)),
+        recorded_path: Arc::new(Mutex::new(Vec::new())),
+        path_recording: Arc::new(AtomicBool::new(false)),
+        path_playing: Arc::new(AtomicBool::new(false)),
+        recorded_rhythm: Arc::new(Mutex::new(Vec::new())),
+        rhythm_recording: Arc::new(AtomicBool::new(false)),
+        rhythm_playing: Arc::new(AtomicBool::new(false)),
+        should_exit: Arc::new(AtomicBool::new(false)),
+        click_generation: Arc::new(AtomicUsize::new(0)),
+        corner_failsafe: Arc::new(AtomicBool::new(config.corner_failsafe)),
+        armed: Arc::new(AtomicBool::new(true)),
+        verbose_logging,
+        theme: config.theme,
+        delay_range: config.delay_range,
+        language: config.language,
+        always_on_top: config.always_on_top,
+        low_power: config.low_power,
+        window_position: config.window_position,
+        window_size: config.window_size,
+        sounds_enabled: config.sounds_enabled,
+        cli_overrides,
+    };
+
+    args.apply_runtime(&flags);
+
+    let workers = [spawn_clicker_loop(flags.clone()), spawn_path_player(flags.clone())];
+
+    // The listener is started exactly once; hotkey changes from the GUI are picked
+    // up through the shared mutexes on the next key event.
+    let listener = start_hotkey_listener(flags.clone());
+
+    if let Some(port) = args.serve_port {
+        api::serve(&args.serve_host, port, flags.clone());
+    }
+
+    if args.headless {
+        println!(
+            "Running headless: press {} to start/stop, Esc to stop, Ctrl+C to quit.",
+            format_combo(&config.hotkey_modifiers, config.hotkey)
+        );
+        let should_exit = flags.should_exit.clone();
+        if let Err(e) = ctrlc::set_handler(move || should_exit.store(true, Ordering::Relaxed)) {
+            println!("Could not handle Ctrl+C ({}); it will end the process directly.", e);
+        }
+        // Everything happens on the background threads. Without the listener
+        // nothing can control a headless run, so its failing ends the run too.
+        while !flags.should_exit.load(Ordering::Relaxed) && !listener.is_finished() {
+            thread::sleep(Duration::from_millis(HEADLESS_POLL_MS));
+        }
+        if listener.is_finished() {
+            println!("Hotkey listener stopped, exiting.");
+        }
+        shut_down(&flags, workers);
+        return Ok(());
+    }
+
+    // A monitor may have been unplugged since the position was saved.
+    let position = match config.window_position {
+        Some((x, y)) if screen::is_visible(x, y) => {
+            iced::window::Position::Specific(iced::Point::new(x as f32, y as f32))
+        }
+        Some(position) => {
+            println!("Saved window position {:?} is off-screen, using the default.", position);
+            iced::window::Position::Default
+        }
+        None => iced::window::Position::Default,
+    };
+    let (width, height) = config
+        .window_size
+        .map_or((WINDOW_WIDTH, WINDOW_HEIGHT), |(width, height)| (width as f32, height as f32));
+
+    let result = AutoClickerApp::run(Settings {
+        flags: flags.clone(),
+        window: iced::window::Settings {
+            // Closing goes through Message::Quit so the config is saved first.
+            exit_on_close_request: false,
+            size: iced::Size::new(width, height),
+            position,
+            level: window_level(config.always_on_top),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+
+    shut_down(&flags, workers);
+    result
+}
+
+// rdev's `listen` blocks forever and has no way to be cancelled, so the listener
+// thread only goes quiet here and ends with the process. The other workers are
+// joined so a held mouse button is always released.
+fn shut_down(flags: &AppFlags, workers: [thread::JoinHandle<()>; 2]) {
+    flags.should_exit.store(true, Ordering::Relaxed);
+    stop_clicker(flags.is_clicking.clone());
+    flags.is_clicking.wake();
+    for worker in workers {
+        let _ = worker.join();
+    }
+    println!("All worker threads stopped, exiting.");
+}
+
+fn spawn_clicker_loop(flags: AppFlags) -> thread::JoinHandle<()> {
+    let AppFlags {
+        is_clicking: flag,
+        delay_ms: delay,
+        jitter_ms: jitter,
+        mouse_button,
+        max_clicks,
+        clicks_done,
+        measured_cps,
+        total_clicks,
+        active_time_us,
+        lifetime_clicks,
+        target_position,
+        start_delay_ms,
+        click_type,
+        jiggle_enabled,
+        jiggle_interval_ms,
+        key_press_mode,
+        key_sequence,
+        burst_enabled,
+        burst_size,
+        burst_pause_ms,
+        click_radius,
+        recorded_points,
+        start_deadline,
+        max_run_ms,
+        run_deadline,
+        stop_at,
+        ramp_enabled,
+        ramp_start_ms,
+        ramp_end_ms,
+        ramp_duration_ms,
+        ramp_shape,
+        ramp_delay_ms,
+        humanize_enabled,
+        humanize_std_dev_ms,
+        scroll_enabled,
+        scroll_direction,
+        scroll_lines,
+        drag_enabled,
+        drag,
+        target_window,
+        input_error,
+        click_error,
+        modifiers,
+        click_pattern,
+        hold_ms,
+        range_enabled,
+        range_min_ms,
+        range_max_ms,
+        focus_target_window,
+        actual_interval_us,
+        interval_jitter_us,
+        recorded_rhythm,
+        rhythm_playing,
+        should_exit,
+        offset_enabled,
+        cursor_offset,
+        click_generation,
+        armed,
+        ..
+    } = flags;
+
+    let (ready_tx, ready_rx) = std::sync::mpsc::channel();
+    let handle = thread::spawn(move || {
+        let enigo = create_enigo();
+        if let Err(e) = &enigo {
+            println!("Input backend unavailable: {}", e);
+            *input_error.lock().unwrap() = Some(e.clone());
+        }
+        let _ = ready_tx.send(());
+        let Ok(mut enigo) = enigo else {
+            // Keep hotkey and tray starts from leaving a "running" clicker that
+            // can't click.
+            while flag.wait_until_running(&should_exit) {
+                stop_clicker(flag.clone());
+            }
+            return;
+        };
+        let mut rng = rand::thread_rng();
+        let mut intervals: VecDeque<Duration> = VecDeque::with_capacity(CPS_WINDOW);
+        let mut burst_clicks = 0;
+        let mut point_index = 0;
+        let mut point_clicks = 0;
+        let mut pattern_index = 0;
+        let mut rhythm_index = 0;
+        let mut key_presses = 0;
+        while flag.wait_until_running(&should_exit) {
+            // Tray, schedule and HTTP starts all end up here, so this is the one
+            // place that has to hold the line while disarmed.
+            if !armed.load(Ordering::Relaxed) {
+                println!("Clicker is disarmed, refusing to start.");
+                flag.set_running(false);
+                continue;
+            }
+            if flag.take_resumed() {
+                println!("Resuming after {} clicks.", clicks_done.load(Ordering::Relaxed));
+            } else {
+                clicks_done.store(0, Ordering::Relaxed);
+                burst_clicks = 0;
+                point_index = 0;
+                point_clicks = 0;
+                pattern_index = 0;
+                rhythm_index = 0;
+                key_presses = 0;
+                *click_error.lock().unwrap() = None;
+            }
+            let mut input_failures = 0;
+            intervals.clear();
+            let mut last_time: Option<std::time::Instant> = None;
+            // When the next click is due. Anything that waits outside the normal
+            // delay clears it, and the timing starts over from the next click.
+            let mut next_click: Option<Instant> = None;
+            // Both ramp shapes begin at the From delay; reset it before the first click
+            // so the readout never shows the previous run's value.
+            ramp_delay_ms.store(ramp_start_ms.load(Ordering::Relaxed), Ordering::Relaxed);
+            let start_delay = start_delay_ms.load(Ordering::Relaxed) as u64;
+            if start_delay > 0 {
+                println!("Starting auto-clicker in {}ms...", start_delay);
+                let wait = Duration::from_millis(start_delay);
+                *start_deadline.lock().unwrap() = Some(std::time::Instant::now() + wait);
+                flag.sleep_while_running(wait);
+                *start_deadline.lock().unwrap() = None;
+            }
+
+            let mut active_checkpoint = std::time::Instant::now();
+            let session_start = active_checkpoint;
+            while flag.is_running() {
+                // Re-read every iteration so the limits can be changed mid-run.
+                let max_run = max_run_ms.load(Ordering::Relaxed) as u64;
+                let deadline = (max_run > 0)
+                    .then(|| session_start + Duration::from_millis(max_run));
+                *run_deadline.lock().unwrap() = deadline;
+                let stop_time = *stop_at.lock().unwrap();
+                let check_stop = |now: Instant| {
+                    let condition = StopCondition::first(
+                        max_clicks.load(Ordering::Relaxed),
+                        clicks_done.load(Ordering::Relaxed),
+                        delay.load(Ordering::Relaxed),
+                        deadline,
+                        stop_time,
+                        now,
+                    );
+                    condition.reached(now).then_some(condition)
+                };
+                if let Some(condition) = check_stop(Instant::now()) {
+                    println!("[AutoClicker] Stop condition reached: {:?}", condition);
+                    if matches!(condition, StopCondition::AtTime(_)) {
+                        *stop_at.lock().unwrap() = None;
+                    }
+                    stop_clicker(flag.clone());
+                    break;
+                }
+                if jiggle_enabled.load(Ordering::Relaxed) {
+                    // Nudge out and straight back so the cursor never drifts.
+                    enigo.mouse_move_relative(JIGGLE_DISTANCE_PX, 0);
+                    std::thread::sleep(Duration::from_millis(JIGGLE_PAUSE_MS));
+                    enigo.mouse_move_relative(-JIGGLE_DISTANCE_PX, 0);
+                    log::debug!("Jiggled.");
+                    let interval = jiggle_interval_ms.load(Ordering::Relaxed) as u64;
+                    flag.sleep_while_running(Duration::from_millis(interval));
+                    next_click = None;
+                    continue;
+                }
+
+                let window_filter = target_window.lock().unwrap().trim().to_lowercase();
+                if !window_filter.is_empty() && !active_window_matches(&window_filter) {
+                    log::debug!("Target window not focused, skipping click.");
+                    let base_delay = delay.load(Ordering::Relaxed) as u64;
+                    flag.sleep_while_running(Duration::from_millis(base_delay));
+                    next_click = None;
+                    continue;
+                }
+
+                // Read the selections every iteration so a change from the GUI
+                // applies to the very next click.
+                let mut dwell_ms = 0;
+                let mut press_ms = 0;
+                let mut sequence_finished = false;
+                let drag_mode = drag_enabled.load(Ordering::Relaxed).then(|| *drag.lock().unwrap());
+                if scroll_enabled.load(Ordering::Relaxed) {
+                    let direction = *scroll_direction.lock().unwrap();
+                    let lines = scroll_lines.load(Ordering::Relaxed) as i32;
+                    enigo.mouse_scroll_y(direction.signed_lines(lines));
+                } else if key_press_mode.load(Ordering::Relaxed) {
+                    let sequence = *key_sequence.lock().unwrap();
+                    let count = sequence.count.max(1);
+                    let cycle_done = match sequence.finish_key {
+                        Some(finish_key) if key_presses >= count => {
+                            enigo.key_click(finish_key.to_enigo_key());
+                            true
+                        }
+                        finish_key => {
+                            enigo.key_click(sequence.key.to_enigo_key());
+                            key_presses += 1;
+                            finish_key.is_none() && key_presses >= count
+                        }
+                    };
+                    if cycle_done {
+                        key_presses = 0;
+                        sequence_finished = !sequence.repeat;
+                    }
+                } else if let Some(drag_mode) = drag_mode {
+                    let Some((from, to)) = drag_mode.endpoints() else {
+                        println!("Drag mode needs both points, stopping.");
+                        *click_error.lock().unwrap() =
+                            Some(String::from("Drag mode needs both a start and an end point"));
+                        stop_clicker(flag.clone());
+                        break;
+                    };
+                    let button = mouse_button.lock().unwrap().to_enigo_button();
+                    match move_to_global(&mut enigo, from.0, from.1) {
+                        Ok(()) => {
+                            enigo.mouse_down(button);
+                            std::thread::sleep(Duration::from_millis(DRAG_STEP_MS));
+                            for (x, y) in DragMode::path(from, to, drag_mode.steps) {
+                                if let Err(e) = move_to_global(&mut enigo, x, y) {
+                                    println!("Drag move failed: {}", e);
+                                    break;
+                                }
+                                std::thread::sleep(Duration::from_millis(DRAG_STEP_MS));
+                            }
+                            // Released even after a failed move so the button is never
+                            // left held down.
+                            enigo.mouse_up(button);
+                        }
+                        Err(e) => println!("Could not reach the drag start: {}", e),
+                    }
+                } else {
+                    let button = {
+                        let pattern = click_pattern.lock().unwrap();
+                        if pattern.is_empty() {
+                            *mouse_button.lock().unwrap()
+                        } else {
+                            pattern_index %= pattern.len();
+                            let button = pattern[pattern_index];
+                            pattern_index += 1;
+                            button
+                        }
+                    };
+                    // A recorded sequence takes precedence over the single target.
+                    let mut target = {
+                        let points = recorded_points.lock().unwrap();
+                        if points.is_empty() {
+                            *target_position.lock().unwrap()
+                        } else {
+                            point_index %= points.len();
+                            let point = points[point_index];
+                            point_clicks += 1;
+                            if point_clicks >= point.clicks.max(1) {
+                                point_clicks = 0;
+                                point_index += 1;
+                                dwell_ms = point.dwell_ms as u64;
+                            }
+                            Some(point.pos)
+                        }
+                    };
+                    let mut return_to = None;
+                    if target.is_none() && offset_enabled.load(Ordering::Relaxed) {
+                        let (cursor_x, cursor_y) = enigo.mouse_location();
+                        let (offset_x, offset_y) = *cursor_offset.lock().unwrap();
+                        return_to = Some((cursor_x, cursor_y));
+                        target = Some((cursor_x + offset_x, cursor_y + offset_y));
+                    }
+                    if let Some((x, y)) = target {
+                        let radius = click_radius.load(Ordering::Relaxed) as u32;
+                        let (dx, dy) = random_offset_in_circle(radius, &mut rng);
+                        log::debug!("Clicking at ({}, {})", x + dx, y + dy);
+                        if focus_target_window.load(Ordering::Relaxed) {
+                            if focus::focus_window_at(x + dx, y + dy) {
+                                std::thread::sleep(Duration::from_millis(FOCUS_SETTLE_MS));
+                            } else {
+                                log::debug!("Could not focus the target window.");
+                            }
+                        }
+                        if let Err(e) = move_to_global(&mut enigo, x + dx, y + dy) {
+                            input_failures += 1;
+                            println!("Input failed ({} in a row): {}", input_failures, e);
+                            if input_failures >= MAX_INPUT_FAILURES {
+                                *click_error.lock().unwrap() = Some(format!(
+                                    "Stopped after {} failed inputs in a row: {}",
+                                    input_failures, e
+                                ));
+                                stop_clicker(flag.clone());
+                                break;
+                            }
+                            let backoff = INPUT_RETRY_BACKOFF_MS * input_failures as u64;
+                            flag.sleep_while_running(Duration::from_millis(backoff));
+                            next_click = None;
+                            continue;
+                        }
+                        input_failures = 0;
+                    }
+                    let click_count = click_type.lock().unwrap().click_count();
+                    let hold = hold_ms.load(Ordering::Relaxed) as u64;
+                    press_ms = press_duration_ms(hold, click_count);
+                    let held_modifiers = modifiers.lock().unwrap().clone();
+                    for modifier in &held_modifiers {
+                        enigo.key_down(modifier.to_enigo_key());
+                    }
+                    for i in 0..click_count {
+                        if i > 0 {
+                            std::thread::sleep(Duration::from_millis(MULTI_CLICK_GAP_MS));
+                        }
+                        if hold == 0 {
+                            enigo.mouse_click(button.to_enigo_button());
+                        } else {
+                            // Stopping cuts the hold short, but the button is always
+                            // released so it's never left stuck down.
+                            enigo.mouse_down(button.to_enigo_button());
+                            flag.sleep_while_running(Duration::from_millis(hold));
+                            enigo.mouse_up(button.to_enigo_button());
+                        }
+                    }
+                    // Nothing above bails out early, so a stop mid-hold still gets
+                    // here and no modifier is left stuck down.
+                    for modifier in held_modifiers.iter().rev() {
+                        enigo.key_up(modifier.to_enigo_key());
+                    }
+                    // Otherwise the next offset would be taken from where we clicked.
+                    if let Some((x, y)) = return_to
+                        && let Err(e) = move_to_global(&mut enigo, x, y)
+                    {
+                        println!("Could not put the cursor back: {}", e);
+                    }
+                }
+                log::debug!("Clicked!");
+                let now = std::time::Instant::now();
+                if let Some(previous) = last_time {
+                    let elapsed = now.duration_since(previous);
+                    log::debug!(
+                        "time since last click: {:?}",
+                        elapsed
+                    );
+                    if intervals.len() == CPS_WINDOW {
+                        intervals.pop_front();
+                    }
+                    intervals.push_back(elapsed);
+                    measured_cps.store(measured_cps_hundredths(&intervals), Ordering::Relaxed);
+                    actual_interval_us.store(average_interval_us(&intervals), Ordering::Relaxed);
+                    interval_jitter_us.store(interval_std_dev_us(&intervals), Ordering::Relaxed);
+                }
+                last_time = Some(now);
+
+                total_clicks.fetch_add(1, Ordering::Relaxed);
+                click_generation.fetch_add(1, Ordering::Relaxed);
+                lifetime_clicks.fetch_add(1, Ordering::Relaxed);
+                active_time_us.fetch_add(
+                    now.duration_since(active_checkpoint).as_micros() as usize,
+                    Ordering::Relaxed,
+                );
+                active_checkpoint = now;
+
+                clicks_done.fetch_add(1, Ordering::Relaxed);
+                // Checked right away too, so a click limit stops without one more delay.
+                if let Some(condition @ StopCondition::MaxClicks { .. }) = check_stop(now) {
+                    println!("[AutoClicker] Stop condition reached: {:?}", condition);
+                    stop_clicker(flag.clone());
+                    break;
+                }
+                if sequence_finished {
+                    println!("[AutoClicker] Key sequence finished.");
+                    stop_clicker(flag.clone());
+                    break;
+                }
+
+                if burst_enabled.load(Ordering::Relaxed) {
+                    burst_clicks += 1;
+                    if burst_clicks >= burst_size.load(Ordering::Relaxed) {
+                        burst_clicks = 0;
+                        let pause = burst_pause_ms.load(Ordering::Relaxed) as u64;
+                        log::debug!("Burst done, pausing {} ms", pause);
+                        flag.sleep_while_running(Duration::from_millis(pause));
+                        // Keep the pause out of the measured CPS.
+                        last_time = None;
+                        next_click = None;
+                        continue;
+                    }
+                }
+                let base_delay = if ramp_enabled.load(Ordering::Relaxed) {
+                    let schedule = match *ramp_shape.lock().unwrap() {
+                        RampShape::Linear => ramped_delay_ms,
+                        RampShape::Alternating => alternating_delay_ms,
+                    };
+                    let base_delay = schedule(
+                        ramp_start_ms.load(Ordering::Relaxed),
+                        ramp_end_ms.load(Ordering::Relaxed),
+                        ramp_duration_ms.load(Ordering::Relaxed),
+                        session_start.elapsed(),
+                    );
+                    ramp_delay_ms.store(base_delay, Ordering::Relaxed);
+                    base_delay
+                } else {
+                    delay.load(Ordering::Relaxed)
+                };
+                let rhythm_interval = if rhythm_playing.load(Ordering::Relaxed) {
+                    let rhythm = recorded_rhythm.lock().unwrap();
+                    (!rhythm.is_empty()).then(|| {
+                        rhythm_index %= rhythm.len();
+                        rhythm_index += 1;
+                        rhythm[rhythm_index - 1]
+                    })
+                } else {
+                    None
+                };
+                // A replayed rhythm overrides every other delay setting. Range mode
+                // ignores the base delay entirely; humanize replaces the uniform
+                // jitter rather than stacking on it.
+                let sleep_time = if let Some(interval) = rhythm_interval {
+                    let sleep_time = interval.as_millis() as u64;
+                    log::debug!("next delay: {} ms (recorded rhythm)", sleep_time);
+                    sleep_time
+                } else if range_enabled.load(Ordering::Relaxed) {
+                    let min = range_min_ms.load(Ordering::Relaxed) as u64;
+                    let max = range_max_ms.load(Ordering::Relaxed).max(min as usize) as u64;
+                    let sleep_time = rng.gen_range(min..=max);
+                    log::debug!(
+                        "next delay: {} ms (range {}-{} ms)",
+                        sleep_time, min, max
+                    );
+                    sleep_time
+                } else if humanize_enabled.load(Ordering::Relaxed) {
+                    let std_dev = humanize_std_dev_ms.load(Ordering::Relaxed);
+                    let sleep_time = humanized_delay_ms(base_delay, std_dev, &mut rng);
+                    log::debug!(
+                        "next delay: {} ms (base {} ms, std dev {} ms)",
+                        sleep_time, base_delay, std_dev
+                    );
+                    sleep_time
+                } else {
+                    let jitter_ms = jitter.load(Ordering::Relaxed);
+                    let sleep_time = jittered_delay_ms(base_delay, jitter_ms, &mut rng);
+                    log::debug!(
+                        "next delay: {} ms (base {} ms, jitter ±{} ms)",
+                        sleep_time, base_delay, jitter_ms
+                    );
+                    sleep_time
+                };
+                // The press time is part of the period so the delay still runs
+                // from the last release to the next press.
+                let period = Duration::from_millis(sleep_time + dwell_ms + press_ms);
+                let due = next_click_due(next_click, period, now);
+                next_click = Some(due);
+                let wake_at = deadline.into_iter().chain(stop_time).fold(due, Instant::min);
+                flag.sleep_while_running(wake_at.saturating_duration_since(Instant::now()));
+            }
+            *run_deadline.lock().unwrap() = None;
+            measured_cps.store(0, Ordering::Relaxed);
+            actual_interval_us.store(0, Ordering::Relaxed);
+            interval_jitter_us.store(0, Ordering::Relaxed);
+            println!("CLICKER THREAD STOPPED.");
+        }
+    });
+    // Wait for the backend so the GUI's first frame can already show a failure.
+    let _ = ready_rx.recv();
+    handle
+}
+
+// Replays the recorded cursor path on a loop while the clicker runs, so the
+// clicks land along it. A fixed target or sequence still moves the cursor for
+// each click on top of this.
+fn spawn_path_player(flags: AppFlags) -> thread::JoinHandle<()> {
+    let AppFlags {
+        is_clicking: flag,
+        recorded_path,
+        path_playing,
+        start_deadline,
+        should_exit,
+        ..
+    } = flags;
+
+    thread::spawn(move || {
+        // The clicker thread already reports a missing backend to the GUI.
+        let Ok(mut enigo) = create_enigo() else {
+            return;
+        };
+        let min_step = Duration::from_millis(PATH_SAMPLE_INTERVAL_MS);
+        while flag.wait_until_running(&should_exit) {
+            let path = recorded_path.lock().unwrap().clone();
+            if !path_playing.load(Ordering::Relaxed)
+                || path.is_empty()
+                || start_deadline.lock().unwrap().is_some()
+            {
+                flag.sleep_while_running(Duration::from_millis(TICK_INTERVAL_MS));
+                continue;
+            }
+            for (x, y, elapsed) in path {
+                if !flag.is_running() || !path_playing.load(Ordering::Relaxed) {
+                    break;
+                }
+                if let Err(e) = move_to_global(&mut enigo, x, y) {
+                    log::debug!("Path step failed: {}", e);
+                }
+                flag.sleep_while_running(elapsed.max(min_step));
+            }
+        }
+    })
+}
+
+// ------------------- Hotkey Handling --------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HotkeyAction {
+    Start,
+    Stop,
+    Toggle,
+}
+
+// The hotkey settings as they are when an event comes in. They are read afresh
+// for every event, so changing a hotkey never needs a new listener.
+#[derive(Debug, Clone, Copy)]
+struct HotkeyBindings {
+    start: Hotkey,
+    stop: Hotkey,
+    mode: ClickMode,
+}
+
+// What the listener remembers between events to turn presses into actions.
+#[derive(Debug, Default)]
+struct HotkeyTracker {
+    // rdev keeps firing KeyPress while a key is held (auto-repeat), so remember
+    // which triggers are already down to avoid re-triggering on every repeat.
+    held_triggers: HashSet<HotkeyTrigger>,
+    // Some platforms still deliver a stray release/press pair for a long press,
+    // which slips past `held_triggers`, so toggles are debounced as well.
+    last_toggle: Option<Instant>,
+}
+
+impl HotkeyTracker {
+    // `combo_held` is whether the start hotkey's modifiers are all down.
+    fn action(
+        &mut self,
+        bindings: HotkeyBindings,
+        combo_held: bool,
+        trigger: HotkeyTrigger,
+        pressed: bool,
+        now: Instant,
+    ) -> Option<HotkeyAction> {
+        let HotkeyBindings { start: start_hotkey, stop: stop_hotkey, mode } = bindings;
+        if !start_hotkey.matches(trigger) && !stop_hotkey.matches(trigger) {
+            return None;
+        }
+        let same_hotkey = start_hotkey == stop_hotkey;
+
+        if pressed {
+            // Without its modifiers the start key does nothing, and neither does
+            // a stop key that is the same key. Hold mode stops on release only.
+            let is_start = start_hotkey.matches(trigger) && combo_held;
+            let is_stop = mode == ClickMode::Toggle && !same_hotkey && stop_hotkey.matches(trigger);
+            if !is_start && !is_stop {
+                return None;
+            }
+            if !self.held_triggers.insert(trigger) {
+                return None;
+            }
+            // Hold mode must always see its press, or a quick tap could leave
+            // the clicker running after the release.
+            if mode == ClickMode::Toggle {
+                let debounce = Duration::from_millis(HOTKEY_DEBOUNCE_MS);
+                if self.last_toggle.is_some_and(|last| now.duration_since(last) < debounce) {
+                    println!("Ignoring hotkey press within debounce window.");
+                    return None;
+                }
+                self.last_toggle = Some(now);
+            }
+            if !is_start {
+                return Some(HotkeyAction::Stop);
+            }
+            // Start key; when both hotkeys are the same it acts as a toggle
+            // so the user can always stop again.
+            match mode {
+                ClickMode::Hold => Some(HotkeyAction::Start),
+                ClickMode::Toggle if same_hotkey => Some(HotkeyAction::Toggle),
+                ClickMode::Toggle => Some(HotkeyAction::Start),
+            }
+        } else {
+            // Only a press that was acted on ends a hold, whatever modifiers are
+            // still down by the time the key comes back up.
+            let was_held = self.held_triggers.remove(&trigger);
+            (was_held && mode == ClickMode::Hold && start_hotkey.matches(trigger))
+                .then_some(HotkeyAction::Stop)
+        }
+    }
+}
+// --------------------------------------------------------
+
+fn start_hotkey_listener(flags: AppFlags) -> thread::JoinHandle<()> {
+    let AppFlags {
+        is_clicking: flag,
+        selected_hotkey,
+        stop_hotkey,
+        hotkey_modifiers,
+        click_mode,
+        mouse_button,
+        click_pattern,
+        preset_hotkey,
+        preset_requests,
+        hotkey_capture,
+        captured_hotkey,
+        position_pick,
+        picked_position,
+        recorded_path,
+        path_recording,
+        recorded_rhythm,
+        rhythm_recording,
+        should_exit,
+        corner_failsafe,
+        armed,
+        listener_error,
+        listener_active,
+        ..
+    } = flags;
+
+    thread::spawn(move || {
+        println!("Hotkey listener started.");
+        let mut hotkeys = HotkeyTracker::default();
+        // Physical modifier keys currently down, so releasing one Shift while the
+        // other is still held keeps Shift held.
+        let mut held_modifier_keys: HashSet<Key> = HashSet::new();
+        let mut last_path_sample = Instant::now();
+        let mut last_rhythm_click: Option<Instant> = None;
+        // Looked up once; a monitor added while running moves the corner only
+        // after a restart.
+        let desktop_origin = screen::desktop_origin();
+        // rdev's button events carry no position, so picks use the last move.
+        let mut last_mouse: Option<(i32, i32)> = None;
+
+        if let Err(e) = listen(move |event| {
+            listener_active.store(true, Ordering::Relaxed);
+            if should_exit.load(Ordering::Relaxed) {
+                return;
+            }
+            if let EventType::MouseMove { x, y } = event.event_type {
+                last_mouse = Some((x as i32, y as i32));
+                if corner_failsafe.load(Ordering::Relaxed)
+                    && in_failsafe_corner(x, y, desktop_origin)
+                    && flag.is_running()
+                {
+                    println!("Mouse moved into the top-left corner, stopping.");
+                    stop_clicker(flag.clone());
+                }
+                if path_recording.load(Ordering::Relaxed) {
+                    let now = Instant::now();
+                    let elapsed = now.duration_since(last_path_sample);
+                    let mut path = recorded_path.lock().unwrap();
+                    if path.is_empty() {
+                        path.push((x as i32, y as i32, Duration::ZERO));
+                        last_path_sample = now;
+                    } else if elapsed >= Duration::from_millis(PATH_SAMPLE_INTERVAL_MS) {
+                        path.push((x as i32, y as i32, elapsed));
+                        last_path_sample = now;
+                    }
+                }
+                return;
+            }
+            // The picking click is used up here, so it can't also trigger a hotkey.
+            if matches!(event.event_type, EventType::ButtonPress(_))
+                && position_pick.swap(false, Ordering::Relaxed)
+            {
+                let position = last_mouse.unwrap_or_else(|| DeviceState::new().get_mouse().coords);
+                *picked_position.lock().unwrap() = Some(position);
+                return;
+            }
+            // Only the user's clicks count; while running most would be our own.
+            if event.event_type == EventType::ButtonPress(Button::Left) {
+                if rhythm_recording.load(Ordering::Relaxed) && !flag.is_running() {
+                    let now = Instant::now();
+                    if let Some(previous) = last_rhythm_click {
+                        recorded_rhythm.lock().unwrap().push(now.duration_since(previous));
+                    }
+                    last_rhythm_click = Some(now);
+                } else {
+                    last_rhythm_click = None;
+                }
+            }
+            let Some((trigger, pressed)) = HotkeyTrigger::from_event(event.event_type) else {
+                return;
+            };
+            let is_modifier_key = match trigger {
+                HotkeyTrigger::Key(key) => Modifier::from_key(key).is_some(),
+                HotkeyTrigger::Button(_) => false,
+            };
+            if let HotkeyTrigger::Key(key) = trigger
+                && is_modifier_key
+            {
+                // Every release is seen here, so nothing stays held once let go.
+                if pressed {
+                    held_modifier_keys.insert(key);
+                } else {
+                    held_modifier_keys.remove(&key);
+                }
+            }
+            let held_modifiers: Vec<Modifier> = Modifier::ALL
+                .into_iter()
+                .filter(|&modifier| {
+                    held_modifier_keys.iter().any(|&key| Modifier::from_key(key) == Some(modifier))
+                })
+                .collect();
+            // The left button is skipped: it's how the user gets around the GUI and
+            // usually what's being auto-clicked. Modifier keys are skipped too, as
+            // they're held for a combination. The GUI applies the capture on its
+            // next tick, with the same conflict checks as the dropdowns.
+            if pressed && trigger != HotkeyTrigger::Button(Button::Left) && !is_modifier_key {
+                let capture = hotkey_capture.lock().unwrap().take();
+                // Esc still goes on to stop the clicker below.
+                if capture.is_some() && trigger == HotkeyTrigger::Key(PANIC_KEY) {
+                    println!("Hotkey capture cancelled.");
+                } else if let Some(capture) = capture {
+                    println!("Captured {} as the {:?} hotkey.", trigger, capture);
+                    let hotkey = Hotkey::from_trigger(trigger);
+                    *captured_hotkey.lock().unwrap() =
+                        Some(CapturedHotkey { capture, hotkey, modifiers: held_modifiers });
+                    return;
+                }
+            }
+            if pressed && trigger == HotkeyTrigger::Key(PANIC_KEY) {
+                if flag.is_running() {
+                    println!("Panic key pressed, stopping.");
+                }
+                stop_clicker(flag.clone());
+                return;
+            }
+            // The presets live in the GUI, so just count the request and let the
+            // next tick apply it.
+            if let Some(preset_key) = *preset_hotkey.lock().unwrap()
+                && preset_key.matches(trigger)
+            {
+                if !pressed {
+                    hotkeys.held_triggers.remove(&trigger);
+                } else if hotkeys.held_triggers.insert(trigger) {
+                    preset_requests.fetch_add(1, Ordering::Relaxed);
+                }
+                return;
+            }
+            if !armed.load(Ordering::Relaxed) {
+                return;
+            }
+            let start_hotkey = *selected_hotkey.lock().unwrap();
+            let stop_hotkey = *stop_hotkey.lock().unwrap();
+            if !start_hotkey.matches(trigger) && !stop_hotkey.matches(trigger) {
+                return;
+            }
+            // Never react to the clicks we're generating ourselves.
+            let clicked_button = *mouse_button.lock().unwrap();
+            let is_own_click = |button: MouseButtonChoice| {
+                trigger == HotkeyTrigger::Button(button.to_rdev_button())
+            };
+            if is_own_click(clicked_button)
+                || click_pattern.lock().unwrap().iter().any(|&button| is_own_click(button))
+            {
+                return;
+            }
+            let bindings = HotkeyBindings {
+                start: start_hotkey,
+                stop: stop_hotkey,
+                mode: *click_mode.lock().unwrap(),
+            };
+            let combo_held = hotkey_modifiers
+                .lock()
+                .unwrap()
+                .iter()
+                .all(|modifier| held_modifiers.contains(modifier));
+            match hotkeys.action(bindings, combo_held, trigger, pressed, Instant::now()) {
+                Some(HotkeyAction::Start) => start_clicker(flag.clone()),
+                Some(HotkeyAction::Stop) => stop_clicker(flag.clone()),
+                Some(HotkeyAction::Toggle) => toggle_clicker(flag.clone()),
+                None => {}
+            }
+        }) {
+            println!("Error listening to keyboard events: {:?}", e);
+            *listener_error.lock().unwrap() = Some(describe_listen_error(&e));
+        }
+    })
+}
+
+// Toggling pauses rather than stops, so a max-click run can be resumed where it
+// left off; the Stop button, stop hotkey and Esc still end the run.
+fn toggle_clicker(flag: Arc<ClickerFlag>) {
+    if flag.is_running() {
+        pause_clicker(flag);
+    } else {
+        start_clicker(flag);
+    }
+}
+
+fn pause_clicker(flag: Arc<ClickerFlag>) {
+    if flag.pause() {
+        println!("Clicker PAUSED.");
+        sound::play(sound::Tone::Stop);
+    }
+}
+
+fn start_clicker(flag: Arc<ClickerFlag>) {
+    if flag.set_running(true) {
+        println!("Clicker STARTED.");
+        sound::play(sound::Tone::Start);
+    }
+}
+
+fn stop_clicker(flag: Arc<ClickerFlag>) {
+    if flag.set_running(false) {
+        println!("Clicker STOPPED.");
+        sound::play(sound::Tone::Stop);
+    }
+}
+
+// Label and message for Start, Pause/Resume and Stop; no message means the button
+// is disabled. Rebuilt on every view, so hotkey and tray changes show up on the
+// next tick.
+fn control_buttons(state: ClickerState, start_blocked: bool) -> [(Label, Option<Message>); 3] {
+    let start = (state != ClickerState::Running && !start_blocked).then_some(Message::StartClicker);
+    let (pause_label, pause) = match state {
+        ClickerState::Running => (Label::Pause, Some(Message::PauseToggle)),
+        ClickerState::Paused => (Label::Resume, Some(Message::PauseToggle)),
+        ClickerState::Stopped => (Label::Pause, None),
+    };
+    let stop = (state != ClickerState::Stopped).then_some(Message::StopClicker);
+    [(Label::Start, start), (pause_label, pause), (Label::Stop, stop)]
+}
+
+// iced already dims a button without `on_press`; the secondary style greys it out
+// as well so it doesn't read as a primary action.
+fn action_button(label: &str, message: Option<Message>) -> iced::widget::Button<'_, Message> {
+    match message {
+        Some(message) => button(label).on_press(message),
+        None => button(label).style(theme::Button::Secondary),
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    StartClicker,
+    ConfirmStart,
+    CancelStart,
+    StopClicker,
+    PauseToggle,
+    SliderChanged(u32),
+    // `true` for "+", which means faster: a shorter delay or more CPS.
+    NudgeSpeed(bool),
+    DelayTextChanged(String),
+    HotkeyChanged(Hotkey),
+    StopHotkeyChanged(Hotkey),
+    PresetHotkeyChanged(Hotkey),
+    ClearPresetHotkey,
+    CaptureHotkey(HotkeyCapture),
+    MouseButtonChanged(MouseButtonChoice),
+    ClickModeChanged(ClickMode),
+    ClickTypeChanged(ClickType),
+    SpeedModeChanged(SpeedMode),
+    DelayRangeChanged(DelayRange),
+    MaxClicksChanged(u32),
+    JitterChanged(u32),
+    HumanizeToggled(bool),
+    RangeToggled(bool),
+    RangeMinChanged(u32),
+    RangeMaxChanged(u32),
+    HumanizeStdDevChanged(u32),
+    StartDelayChanged(u32),
+    HoldChanged(u32),
+    JiggleToggled(bool),
+    KeyPressModeToggled(bool),
+    ScrollToggled(bool),
+    ModifierToggled(Modifier, bool),
+    HotkeyModifierToggled(Modifier, bool),
+    ScrollDirectionChanged(ScrollDirection),
+    ScrollLinesChanged(u32),
+    DragToggled(bool),
+    SetDragFrom,
+    SetDragTo,
+    DragStepsChanged(u32),
+    PressKeyChanged(PressKey),
+    PressCountChanged(String),
+    FinishKeyChanged(PressKey),
+    ClearFinishKey,
+    RepeatCycleToggled(bool),
+    JiggleIntervalChanged(u32),
+    BurstToggled(bool),
+    RampToggled(bool),
+    RampStartChanged(u32),
+    RampEndChanged(u32),
+    RampDurationChanged(u32),
+    RampShapeChanged(RampShape),
+    BurstSizeChanged(u32),
+    BurstPauseChanged(u32),
+    ResetStats,
+    TargetXChanged(String),
+    TargetYChanged(String),
+    TargetWindowChanged(String),
+    CapturePosition,
+    PickPosition,
+    ClickRadiusChanged(u32),
+    MaxRunChanged(u32),
+    FocusTargetToggled(bool),
+    OverlayToggled(bool),
+    OffsetToggled(bool),
+    OffsetXChanged(String),
+    PatternChanged(String),
+    OffsetYChanged(String),
+    ScheduleStartChanged(String),
+    ScheduleRunChanged(String),
+    ArmSchedule,
+    CancelSchedule,
+    AddRecordedPoint,
+    ClearRecordedPoints,
+    PointClicksChanged(usize, u32),
+    PointDwellChanged(usize, u32),
+    RemoveRecordedPoint(usize),
+    RecordPath,
+    StopPath,
+    PlayPath,
+    ClearPath,
+    RecordRhythm,
+    StopRhythm,
+    ReplayRhythm,
+    ClearRhythm,
+    ThemeChanged(ThemeChoice),
+    LanguageChanged(Language),
+    SoundsToggled(bool),
+    CornerFailsafeToggled(bool),
+    ArmToggle,
+    TestClick,
+    VerboseLoggingToggled(bool),
+    AlwaysOnTopToggled(bool),
+    LowPowerToggled(bool),
+    // Only remembered here; written out with the rest of the config on exit.
+    WindowMoved(i32, i32),
+    WindowResized(u32, u32),
+    PresetSelected(String),
+    PresetNameChanged(String),
+    SavePreset,
+    DeletePreset,
+    ExportProfile,
+    ImportProfile,
+    Quit,
+    ClearTarget,
+    Tick,
+}
+
+// State shared between `main`, the background threads and the GUI.
+#[derive(Clone, Default)]
+struct AppFlags {
+    is_clicking: Arc<ClickerFlag>,
+    delay_ms: Arc<AtomicUsize>,
+    jitter_ms: Arc<AtomicUsize>,
+    selected_hotkey: Arc<Mutex<Hotkey>>,
+    stop_hotkey: Arc<Mutex<Hotkey>>,
+    hotkey_modifiers: Arc<Mutex<Vec<Modifier>>>,
+    mouse_button: Arc<Mutex<MouseButtonChoice>>,
+    click_mode: Arc<Mutex<ClickMode>>,
+    max_clicks: Arc<AtomicUsize>,
+    clicks_done: Arc<AtomicUsize>,
+    measured_cps: Arc<AtomicUsize>,
+    total_clicks: Arc<AtomicUsize>,
+    active_time_us: Arc<AtomicUsize>,
+    lifetime_clicks: Arc<AtomicUsize>,
+    // `None` clicks wherever the cursor currently is.
+    target_position: Arc<Mutex<Option<(i32, i32)>>>,
+    start_delay_ms: Arc<AtomicUsize>,
+    click_type: Arc<Mutex<ClickType>>,
+    jiggle_enabled: Arc<AtomicBool>,
+    jiggle_interval_ms: Arc<AtomicUsize>,
+    key_press_mode: Arc<AtomicBool>,
+    key_sequence: Arc<Mutex<KeySequence>>,
+    burst_enabled: Arc<AtomicBool>,
+    burst_size: Arc<AtomicUsize>,
+    burst_pause_ms: Arc<AtomicUsize>,
+    click_radius: Arc<AtomicUsize>,
+    recorded_points: Arc<Mutex<Vec<SequencePoint>>>,
+    // Set while the clicker waits out its start delay, so the GUI can count down.
+    start_deadline: Arc<Mutex<Option<Instant>>>,
+    max_run_ms: Arc<AtomicUsize>,
+    // When the current run hits `max_run_ms`; `None` while stopped or unlimited.
+    run_deadline: Arc<Mutex<Option<Instant>>>,
+    // A scheduled stop; unlike `run_deadline` it survives a pause.
+    stop_at: Arc<Mutex<Option<Instant>>>,
+    ramp_enabled: Arc<AtomicBool>,
+    ramp_start_ms: Arc<AtomicUsize>,
+    ramp_end_ms: Arc<AtomicUsize>,
+    ramp_duration_ms: Arc<AtomicUsize>,
+    ramp_shape: Arc<Mutex<RampShape>>,
+    ramp_delay_ms: Arc<AtomicUsize>,
+    humanize_enabled: Arc<AtomicBool>,
+    humanize_std_dev_ms: Arc<AtomicUsize>,
+    scroll_enabled: Arc<AtomicBool>,
+    scroll_direction: Arc<Mutex<ScrollDirection>>,
+    scroll_lines: Arc<AtomicUsize>,
+    drag_enabled: Arc<AtomicBool>,
+    drag: Arc<Mutex<DragMode>>,
+    target_window: Arc<Mutex<String>>,
+    input_error: Arc<Mutex<Option<String>>>,
+    // Why the clicker last stopped itself after repeated input failures; cleared
+    // on the next fresh start.
+    click_error: Arc<Mutex<Option<String>>>,
+    // Set if the global hotkey listener couldn't start.
+    listener_error: Arc<Mutex<Option<String>>>,
+    // Set by the first event the listener receives, which proves the hook works.
+    listener_active: Arc<AtomicBool>,
+    // Held down around every mouse click, kept in `Modifier::ALL` order.
+    modifiers: Arc<Mutex<Vec<Modifier>>>,
+    click_pattern: Arc<Mutex<Vec<MouseButtonChoice>>>,
+    hold_ms: Arc<AtomicUsize>,
+    range_enabled: Arc<AtomicBool>,
+    range_min_ms: Arc<AtomicUsize>,
+    range_max_ms: Arc<AtomicUsize>,
+    focus_target_window: Arc<AtomicBool>,
+    // Clicks land this far from the cursor, which is put back afterwards.
+    offset_enabled: Arc<AtomicBool>,
+    cursor_offset: Arc<Mutex<(i32, i32)>>,
+    corner_failsafe: Arc<AtomicBool>,
+    // Master switch: while false the start/stop hotkeys do nothing and the clicker
+    // won't run, but every setting is left as it is.
+    armed: Arc<AtomicBool>,
+    preset_hotkey: Arc<Mutex<Option<Hotkey>>>,
+    // Number of "next preset" hotkey presses not yet handled by the GUI.
+    preset_requests: Arc<AtomicUsize>,
+    // While set, the listener binds the next press instead of acting on it and
+    // leaves the result in `captured_hotkey`.
+    hotkey_capture: Arc<Mutex<Option<HotkeyCapture>>>,
+    captured_hotkey: Arc<Mutex<Option<CapturedHotkey>>>,
+    // While set, the listener takes the position of the next mouse click and
+    // leaves it in `picked_position`.
+    position_pick: Arc<AtomicBool>,
+    picked_position: Arc<Mutex<Option<(i32, i32)>>>,
+    // Average click-to-click time over the CPS window, including enigo's own overhead.
+    actual_interval_us: Arc<AtomicUsize>,
+    interval_jitter_us: Arc<AtomicUsize>,
+    // Cursor positions with the time since the previous sample.
+    recorded_path: Arc<Mutex<Vec<(i32, i32, Duration)>>>,
+    path_recording: Arc<AtomicBool>,
+    path_playing: Arc<AtomicBool>,
+    recorded_rhythm: Arc<Mutex<Vec<Duration>>>,
+    rhythm_recording: Arc<AtomicBool>,
+    rhythm_playing: Arc<AtomicBool>,
+    // Set once the window has closed; the worker threads finish up and return.
+    should_exit: Arc<AtomicBool>,
+    // Bumped on every click, only ever compared for change by the GUI.
+    click_generation: Arc<AtomicUsize>,
+    verbose_logging: bool,
+    theme: ThemeChoice,
+    delay_range: DelayRange,
+    language: Language,
+    sounds_enabled: bool,
+    always_on_top: bool,
+    low_power: bool,
+    window_position: Option<(i32, i32)>,
+    window_size: Option<(u32, u32)>,
+    // Command line settings for this run only; see `save_config`.
+    cli_overrides: cli::CliOverrides,
+}
+
+struct AutoClickerApp {
+    is_clicking: Arc<ClickerFlag>,
+    delay_ms: Arc<AtomicUsize>,
+    jitter_ms: Arc<AtomicUsize>,
+    slider_value: u32,
+    delay_input: String,
+    speed_mode: SpeedMode,
+    delay_range: DelayRange,
+    selected_hotkey: Arc<Mutex<Hotkey>>,
+    stop_hotkey: Arc<Mutex<Hotkey>>,
+    hotkey_modifiers: Arc<Mutex<Vec<Modifier>>>,
+    mouse_button: Arc<Mutex<MouseButtonChoice>>,
+    click_mode: Arc<Mutex<ClickMode>>,
+    max_clicks: Arc<AtomicUsize>,
+    clicks_done: Arc<AtomicUsize>,
+    measured_cps: Arc<AtomicUsize>,
+    total_clicks: Arc<AtomicUsize>,
+    active_time_us: Arc<AtomicUsize>,
+    lifetime_clicks: Arc<AtomicUsize>,
+    target_position: Arc<Mutex<Option<(i32, i32)>>>,
+    target_x_input: String,
+    target_y_input: String,
+    show_overlay: bool,
+    press_count_input: String,
+    press_count_invalid: bool,
+    start_delay_ms: Arc<AtomicUsize>,
+    click_type: Arc<Mutex<ClickType>>,
+    jiggle_enabled: Arc<AtomicBool>,
+    jiggle_interval_ms: Arc<AtomicUsize>,
+    key_press_mode: Arc<AtomicBool>,
+    key_sequence: Arc<Mutex<KeySequence>>,
+    burst_enabled: Arc<AtomicBool>,
+    burst_size: Arc<AtomicUsize>,
+    burst_pause_ms: Arc<AtomicUsize>,
+    click_radius: Arc<AtomicUsize>,
+    recorded_points: Arc<Mutex<Vec<SequencePoint>>>,
+    start_deadline: Arc<Mutex<Option<Instant>>>,
+    max_run_ms: Arc<AtomicUsize>,
+    // When the current run hits `max_run_ms`; `None` while stopped or unlimited.
+    run_deadline: Arc<Mutex<Option<Instant>>>,
+    // A scheduled stop; unlike `run_deadline` it survives a pause.
+    stop_at: Arc<Mutex<Option<Instant>>>,
+    ramp_enabled: Arc<AtomicBool>,
+    ramp_start_ms: Arc<AtomicUsize>,
+    ramp_end_ms: Arc<AtomicUsize>,
+    ramp_duration_ms: Arc<AtomicUsize>,
+    ramp_shape: Arc<Mutex<RampShape>>,
+    ramp_delay_ms: Arc<AtomicUsize>,
+    humanize_enabled: Arc<AtomicBool>,
+    humanize_std_dev_ms: Arc<AtomicUsize>,
+    scroll_enabled: Arc<AtomicBool>,
+    scroll_direction: Arc<Mutex<ScrollDirection>>,
+    scroll_lines: Arc<AtomicUsize>,
+    drag_enabled: Arc<AtomicBool>,
+    drag: Arc<Mutex<DragMode>>,
+    target_window: Arc<Mutex<String>>,
+    input_error: Arc<Mutex<Option<String>>>,
+    // Why the clicker last stopped itself after repeated input failures; cleared
+    // on the next fresh start.
+    click_error: Arc<Mutex<Option<String>>>,
+    // Set if the global hotkey listener couldn't start.
+    listener_error: Arc<Mutex<Option<String>>>,
+    // Set by the first event the listener receives, which proves the hook works.
+    listener_active: Arc<AtomicBool>,
+    // Held down around every mouse click, kept in `Modifier::ALL` order.
+    modifiers: Arc<Mutex<Vec<Modifier>>>,
+    click_pattern: Arc<Mutex<Vec<MouseButtonChoice>>>,
+    hold_ms: Arc<AtomicUsize>,
+    range_enabled: Arc<AtomicBool>,
+    range_min_ms: Arc<AtomicUsize>,
+    range_max_ms: Arc<AtomicUsize>,
+    focus_target_window: Arc<AtomicBool>,
+    offset_enabled: Arc<AtomicBool>,
+    cursor_offset: Arc<Mutex<(i32, i32)>>,
+    corner_failsafe: Arc<AtomicBool>,
+    armed: Arc<AtomicBool>,
+    pattern_input: String,
+    // The first character of `pattern_input` that isn't a button letter.
+    pattern_error: Option<char>,
+    // Outcome of the last Test click, written by its thread.
+    test_click: Arc<Mutex<Option<TestClickStatus>>>,
+    offset_x_input: String,
+    offset_y_input: String,
+    preset_hotkey: Arc<Mutex<Option<Hotkey>>>,
+    preset_requests: Arc<AtomicUsize>,
+    hotkey_capture: Arc<Mutex<Option<HotkeyCapture>>>,
+    captured_hotkey: Arc<Mutex<Option<CapturedHotkey>>>,
+    position_pick: Arc<AtomicBool>,
+    picked_position: Arc<Mutex<Option<(i32, i32)>>>,
+    actual_interval_us: Arc<AtomicUsize>,
+    interval_jitter_us: Arc<AtomicUsize>,
+    recorded_path: Arc<Mutex<Vec<(i32, i32, Duration)>>>,
+    path_recording: Arc<AtomicBool>,
+    path_playing: Arc<AtomicBool>,
+    recorded_rhythm: Arc<Mutex<Vec<Duration>>>,
+    rhythm_recording: Arc<AtomicBool>,
+    rhythm_playing: Arc<AtomicBool>,
+    click_generation: Arc<AtomicUsize>,
+    // The generation seen on the last tick; the indicator lights if it moved since.
+    seen_click_generation: usize,
+    click_flash: bool,
+    verbose_logging: bool,
+    // Set when Start was pressed at a delay below CONFIRM_BELOW_DELAY_MS.
+    pending_confirm: bool,
+    hotkey_warning: Option<String>,
+    profile_status: Option<String>,
+    presets: HashMap<String, Profile>,
+    schedule_start_input: String,
+    schedule_run_input: String,
+    scheduled_start: Option<Instant>,
+    // A settings change is waiting to be written; pushed back by every new one.
+    config_save_due: Option<Instant>,
+    // Minutes to run once the scheduled start fires. Turned into `stop_at` at that
+    // point, so a delayed start doesn't eat into the run time.
+    scheduled_run_minutes: Option<u64>,
+    selected_preset: Option<String>,
+    preset_name_input: String,
+    sounds_enabled: bool,
+    theme: ThemeChoice,
+    language: Language,
+    always_on_top: bool,
+    low_power: bool,
+    window_position: Option<(i32, i32)>,
+    window_size: Option<(u32, u32)>,
+    cli_overrides: cli::CliOverrides,
+    tray: Option<tray::Tray>,
+}
+
+impl AutoClickerApp {
+    // Both fields must hold a valid number for the target to be used; anything else
+    // falls back to clicking at the cursor.
+    fn apply_target_inputs(&mut self) {
+        let target = match (
+            self.target_x_input.trim().parse::<i32>(),
+            self.target_y_input.trim().parse::<i32>(),
+        ) {
+            (Ok(x), Ok(y)) => Some((x, y)),
+            _ => None,
+        };
+        *self.target_position.lock().unwrap() = target;
+        match target {
+            Some((x, y)) => println!("Click target set to ({}, {})", x, y),
+            None => println!("Click target cleared, clicking at cursor."),
+        }
+        self.sync_overlay();
+    }
+
+    // The overlay marks the fixed target only; there's nothing to mark when
+    // clicking at the cursor.
+    fn sync_overlay(&self) {
+        match *self.target_position.lock().unwrap() {
+            Some((x, y)) if self.show_overlay => overlay::show_at(x, y),
+            _ => overlay::hide(),
+        }
+    }
+
+    // Anything that doesn't parse counts as 0 so the other axis still applies.
+    fn apply_offset_inputs(&mut self) {
+        let offset = (
+            self.offset_x_input.trim().parse::<i32>().unwrap_or(0),
+            self.offset_y_input.trim().parse::<i32>().unwrap_or(0),
+        );
+        *self.cursor_offset.lock().unwrap() = offset;
+        println!("Cursor offset set to {:?}", offset);
+    }
+
+    // The button `hotkey` would collide with: the selected one or any in the pattern.
+    fn clicked_button_conflict(&self, hotkey: Hotkey) -> Option<MouseButtonChoice> {
+        let selected = *self.mouse_button.lock().unwrap();
+        let pattern = self.click_pattern.lock().unwrap();
+        std::iter::once(selected)
+            .chain(pattern.iter().copied())
+            .find(|&button| hotkey.conflicts_with(button))
+    }
+
+    fn reject_hotkey_conflict(&mut self, hotkey: Hotkey, button: MouseButtonChoice) {
+        let warning = format!(
+            "{} can't be a hotkey while auto-clicking the {} button.",
+            hotkey, button
+        );
+        println!("{}", warning);
+        self.hotkey_warning = Some(warning);
+    }
+
+    fn sync_slider(&mut self) {
+        let delay = self.delay_ms.load(Ordering::Relaxed) as u32;
+        self.slider_value = match self.speed_mode {
+            SpeedMode::Milliseconds => delay,
+            SpeedMode::ClicksPerSecond => delay_ms_to_cps(delay),
+        };
+    }
+
+    fn current_profile(&self) -> Profile {
+        Profile {
+            delay_ms: self.delay_ms.load(Ordering::Relaxed) as u32,
+            jitter_ms: self.jitter_ms.load(Ordering::Relaxed) as u32,
+            start_delay_ms: self.start_delay_ms.load(Ordering::Relaxed) as u32,
+            hotkey: *self.selected_hotkey.lock().unwrap(),
+            stop_hotkey: *self.stop_hotkey.lock().unwrap(),
+            hotkey_modifiers: self.hotkey_modifiers.lock().unwrap().clone(),
+            mouse_button: *self.mouse_button.lock().unwrap(),
+            click_mode: *self.click_mode.lock().unwrap(),
+            click_type: *self.click_type.lock().unwrap(),
+            target_position: *self.target_position.lock().unwrap(),
+            click_radius: self.click_radius.load(Ordering::Relaxed) as u32,
+            recorded_points: self.recorded_points.lock().unwrap().clone(),
+            recorded_path: self.recorded_path.lock().unwrap().clone(),
+            click_pattern: self.click_pattern.lock().unwrap().clone(),
+            recorded_rhythm: self.recorded_rhythm.lock().unwrap().clone(),
+            cursor_offset: self
+                .offset_enabled
+                .load(Ordering::Relaxed)
+                .then(|| *self.cursor_offset.lock().unwrap()),
+            drag: self
+                .drag_enabled
+                .load(Ordering::Relaxed)
+                .then(|| *self.drag.lock().unwrap()),
+            key_sequence: self
+                .key_press_mode
+                .load(Ordering::Relaxed)
+                .then(|| *self.key_sequence.lock().unwrap()),
+        }
+    }
+
+    fn apply_profile(&mut self, profile: Profile) {
+        let delay = clamp_delay_ms(profile.delay_ms);
+        self.delay_ms.store(delay as usize, Ordering::Relaxed);
+        self.jitter_ms.store(profile.jitter_ms as usize, Ordering::Relaxed);
+        self.start_delay_ms.store(profile.start_delay_ms as usize, Ordering::Relaxed);
+        *self.selected_hotkey.lock().unwrap() = profile.hotkey;
+        *self.hotkey_modifiers.lock().unwrap() = profile.hotkey_modifiers;
+        *self.stop_hotkey.lock().unwrap() = profile.stop_hotkey;
+        *self.mouse_button.lock().unwrap() = profile.mouse_button;
+        *self.click_mode.lock().unwrap() = profile.click_mode;
+        *self.click_type.lock().unwrap() = profile.click_type;
+        self.click_radius.store(profile.click_radius as usize, Ordering::Relaxed);
+        *self.recorded_points.lock().unwrap() = profile.recorded_points;
+        self.path_recording.store(false, Ordering::Relaxed);
+        *self.recorded_path.lock().unwrap() = profile.recorded_path;
+        self.pattern_input = format_click_pattern(&profile.click_pattern);
+        self.pattern_error = None;
+        *self.click_pattern.lock().unwrap() = profile.click_pattern;
+        self.rhythm_recording.store(false, Ordering::Relaxed);
+        *self.recorded_rhythm.lock().unwrap() = profile.recorded_rhythm;
+
+        // Refresh the widgets that keep their own copy of a value.
+        self.delay_input = delay.to_string();
+        self.sync_slider();
+        let (x, y) = match profile.target_position {
+            Some((x, y)) => (x.to_string(), y.to_string()),
+            None => (String::new(), String::new()),
+        };
+        self.target_x_input = x;
+        self.target_y_input = y;
+        self.apply_target_inputs();
+        self.offset_enabled.store(profile.cursor_offset.is_some(), Ordering::Relaxed);
+        let (offset_x, offset_y) = profile.cursor_offset.unwrap_or_default();
+        self.offset_x_input = offset_x.to_string();
+        self.offset_y_input = offset_y.to_string();
+        self.apply_offset_inputs();
+        self.drag_enabled.store(profile.drag.is_some(), Ordering::Relaxed);
+        if let Some(drag) = profile.drag {
+            *self.drag.lock().unwrap() = drag;
+        }
+        self.key_press_mode.store(profile.key_sequence.is_some(), Ordering::Relaxed);
+        if let Some(mut sequence) = profile.key_sequence {
+            // Hand-edited profiles can hold anything.
+            sequence.count = sequence.count.max(1);
+            self.press_count_input = sequence.count.to_string();
+            self.press_count_invalid = false;
+            *self.key_sequence.lock().unwrap() = sequence;
+        }
+        self.hotkey_warning = None;
+    }
+
+    fn tick_interval_ms(&self) -> u64 {
+        // Recording, a pending schedule, a hotkey capture and a test click all wait
+        // on the tick as well, so they keep the responsive rate like a running clicker.
+        let busy = self.is_clicking.is_running()
+            || self.scheduled_start.is_some()
+            || self.path_recording.load(Ordering::Relaxed)
+            || self.rhythm_recording.load(Ordering::Relaxed)
+            || self.hotkey_capture.lock().unwrap().is_some()
+            || self.position_pick.load(Ordering::Relaxed)
+            || matches!(*self.test_click.lock().unwrap(), Some(TestClickStatus::Pending));
+        if busy {
+            TICK_INTERVAL_MS
+        } else if self.low_power {
+            LOW_POWER_IDLE_TICK_INTERVAL_MS
+        } else {
+            IDLE_TICK_INTERVAL_MS
+        }
+    }
+
+    // Sorted, so the preset hotkey cycles in the same order as the dropdown.
+    fn preset_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.presets.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    fn select_next_preset(&mut self) {
+        let names = self.preset_names();
+        let next = match &self.selected_preset {
+            Some(current) => names
+                .iter()
+                .position(|name| name == current)
+                .map_or(0, |index| (index + 1) % names.len()),
+            None => 0,
+        };
+        if let Some(name) = names.into_iter().nth(next) {
+            let _ = self.update(Message::PresetSelected(name));
+        }
+    }
+
+    fn save_config(&self) {
+        let mut config = Config {
+            delay_ms: self.delay_ms.load(Ordering::Relaxed) as u32,
+            hotkey: *self.selected_hotkey.lock().unwrap(),
+            stop_hotkey: *self.stop_hotkey.lock().unwrap(),
+            hotkey_modifiers: self.hotkey_modifiers.lock().unwrap().clone(),
+            mouse_button: *self.mouse_button.lock().unwrap(),
+            click_mode: *self.click_mode.lock().unwrap(),
+            lifetime_clicks: self.lifetime_clicks.load(Ordering::Relaxed) as u64,
+            preset_hotkey: *self.preset_hotkey.lock().unwrap(),
+            theme: self.theme,
+            delay_range: self.delay_range,
+            language: self.language,
+            sounds_enabled: self.sounds_enabled,
+            corner_failsafe: self.corner_failsafe.load(Ordering::Relaxed),
+            always_on_top: self.always_on_top,
+            low_power: self.low_power,
+            window_position: self.window_position,
+            window_size: self.window_size,
+            max_run_ms: self.max_run_ms.load(Ordering::Relaxed) as u32,
+        };
+        // The command line only overrides settings for this run.
+        self.cli_overrides.restore(&mut config);
+        config.save();
+    }
+}
+
+impl Application for AutoClickerApp {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Theme = theme::Theme;
+    type Flags = AppFlags;
+
+    fn new(flags: Self::Flags) -> (Self, Command<Self::Message>) {
+        let slider_value = flags.delay_ms.load(Ordering::Relaxed) as u32;
+        let tray = tray::Tray::new(flags.is_clicking.clone());
+        // Set by --target; otherwise the fields start empty.
+        let target = *flags.target_position.lock().unwrap();
+        (
+            Self {
+                is_clicking: flags.is_clicking,
+                delay_ms: flags.delay_ms,
+                jitter_ms: flags.jitter_ms,
+                slider_value,
+                delay_input: slider_value.to_string(),
+                speed_mode: SpeedMode::default(),
+                delay_range: flags.delay_range,
+                selected_hotkey: flags.selected_hotkey,
+                stop_hotkey: flags.stop_hotkey,
+                hotkey_modifiers: flags.hotkey_modifiers,
+                mouse_button: flags.mouse_button,
+                click_mode: flags.click_mode,
+                max_clicks: flags.max_clicks,
+                clicks_done: flags.clicks_done,
+                measured_cps: flags.measured_cps,
+                total_clicks: flags.total_clicks,
+                active_time_us: flags.active_time_us,
+                lifetime_clicks: flags.lifetime_clicks,
+                target_position: flags.target_position,
+                target_x_input: target.map(|(x, _)| x.to_string()).unwrap_or_default(),
+                show_overlay: false,
+                target_y_input: target.map(|(_, y)| y.to_string()).unwrap_or_default(),
+                start_delay_ms: flags.start_delay_ms,
+                click_type: flags.click_type,
+                jiggle_enabled: flags.jiggle_enabled,
+                jiggle_interval_ms: flags.jiggle_interval_ms,
+                key_press_mode: flags.key_press_mode,
+                key_sequence: flags.key_sequence,
+                press_count_input: String::from("1"),
+                press_count_invalid: false,
+                burst_enabled: flags.burst_enabled,
+                burst_size: flags.burst_size,
+                burst_pause_ms: flags.burst_pause_ms,
+                click_radius: flags.click_radius,
+                recorded_points: flags.recorded_points,
+                start_deadline: flags.start_deadline,
+                max_run_ms: flags.max_run_ms,
+                run_deadline: flags.run_deadline,
+                stop_at: flags.stop_at,
+                ramp_enabled: flags.ramp_enabled,
+                ramp_start_ms: flags.ramp_start_ms,
+                ramp_end_ms: flags.ramp_end_ms,
+                ramp_duration_ms: flags.ramp_duration_ms,
+                ramp_shape: flags.ramp_shape,
+                ramp_delay_ms: flags.ramp_delay_ms,
+                humanize_enabled: flags.humanize_enabled,
+                humanize_std_dev_ms: flags.humanize_std_dev_ms,
+                scroll_enabled: flags.scroll_enabled,
+                scroll_direction: flags.scroll_direction,
+                scroll_lines: flags.scroll_lines,
+                drag_enabled: flags.drag_enabled,
+                drag: flags.drag,
+                target_window: flags.target_window,
+                input_error: flags.input_error,
+                click_error: flags.click_error,
+                listener_error: flags.listener_error,
+                listener_active: flags.listener_active,
+                test_click: Arc::new(Mutex::new(None)),
+                modifiers: flags.modifiers,
+                click_pattern: flags.click_pattern,
+                pattern_input: String::new(),
+                pattern_error: None,
+                hold_ms: flags.hold_ms,
+                range_enabled: flags.range_enabled,
+                range_min_ms: flags.range_min_ms,
+                range_max_ms: flags.range_max_ms,
+                focus_target_window: flags.focus_target_window,
+                offset_enabled: flags.offset_enabled,
+                cursor_offset: flags.cursor_offset,
+                corner_failsafe: flags.corner_failsafe,
+                armed: flags.armed,
+                offset_x_input: String::from("0"),
+                offset_y_input: String::from("0"),
+                preset_hotkey: flags.preset_hotkey,
+                preset_requests: flags.preset_requests,
+                hotkey_capture: flags.hotkey_capture,
+                captured_hotkey: flags.captured_hotkey,
+                position_pick: flags.position_pick,
+                picked_position: flags.picked_position,
+                actual_interval_us: flags.actual_interval_us,
+                interval_jitter_us: flags.interval_jitter_us,
+                recorded_path: flags.recorded_path,
+                path_recording: flags.path_recording,
+                path_playing: flags.path_playing,
+                recorded_rhythm: flags.recorded_rhythm,
+                rhythm_recording: flags.rhythm_recording,
+                rhythm_playing: flags.rhythm_playing,
+                click_generation: flags.click_generation,
+                seen_click_generation: 0,
+                click_flash: false,
+                verbose_logging: flags.verbose_logging,
+                pending_confirm: false,
+                hotkey_warning: None,
+                profile_status: None,
+                presets: config::load_presets(),
+                schedule_start_input: String::new(),
+                schedule_run_input: String::new(),
+                scheduled_start: None,
+                config_save_due: None,
+                scheduled_run_minutes: None,
+                selected_preset: None,
+                preset_name_input: String::new(),
+                sounds_enabled: flags.sounds_enabled,
+                theme: flags.theme,
+                language: flags.language,
+                always_on_top: flags.always_on_top,
+                low_power: flags.low_power,
+                window_position: flags.window_position,
+                window_size: flags.window_size,
+                cli_overrides: flags.cli_overrides,
+                tray,
+            },
+            Command::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Rust Auto Clicker")
+    }
+
+    fn theme(&self) -> Self::Theme {
+        self.theme.to_theme()
+    }
+
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
+        if !matches!(
+            message,
+            Message::Tick | Message::WindowMoved(..) | Message::WindowResized(..)
+        ) {
+            log::debug!("Received message: {:?}", message);
+        }
+
+        let persist = matches!(
+            message,
+            Message::SliderChanged(_)
+                | Message::DelayTextChanged(_)
+                | Message::StopClicker
+                | Message::ResetStats
+                | Message::HotkeyChanged(_)
+                | Message::StopHotkeyChanged(_)
+                | Message::HotkeyModifierToggled(..)
+                | Message::PresetHotkeyChanged(_)
+                | Message::ClearPresetHotkey
+                | Message::MouseButtonChanged(_)
+                | Message::ClickModeChanged(_)
+                | Message::ThemeChanged(_)
+                | Message::DelayRangeChanged(_)
+                | Message::LanguageChanged(_)
+                | Message::SoundsToggled(_)
+                | Message::CornerFailsafeToggled(_)
+                | Message::AlwaysOnTopToggled(_)
+                | Message::LowPowerToggled(_)
+                | Message::MaxRunChanged(_)
+                | Message::ImportProfile
+                | Message::PresetSelected(_)
+        );
+        if persist {
+            self.config_save_due =
+                Some(Instant::now() + Duration::from_millis(CONFIG_SAVE_DEBOUNCE_MS));
+        }
+
+        match message {
+            Message::StartClicker => {
+                let delay = self.delay_ms.load(Ordering::Relaxed) as u32;
+                if delay < CONFIRM_BELOW_DELAY_MS {
+                    self.pending_confirm = true;
+                    println!("Delay of {} ms needs confirmation before starting.", delay);
+                } else {
+                    start_clicker(self.is_clicking.clone());
+                }
+            }
+            Message::ConfirmStart => {
+                self.pending_confirm = false;
+                start_clicker(self.is_clicking.clone());
+            }
+            Message::CancelStart => {
+                self.pending_confirm = false;
+                println!("High-rate start cancelled.");
+            }
+            Message::StopClicker => {
+                self.pending_confirm = false;
+                stop_clicker(self.is_clicking.clone());
+            }
+            Message::PauseToggle => toggle_clicker(self.is_clicking.clone()),
+            Message::TestClick => {
+                if matches!(*self.test_click.lock().unwrap(), Some(TestClickStatus::Pending)) {
+                    println!("Test click already pending, ignoring.");
+                } else {
+                    let clicked_button = match self.click_pattern.lock().unwrap().first() {
+                        Some(&button) => button,
+                        None => *self.mouse_button.lock().unwrap(),
+                    };
+                    spawn_test_click(
+                        clicked_button,
+                        *self.target_position.lock().unwrap(),
+                        self.offset_enabled
+                            .load(Ordering::Relaxed)
+                            .then(|| *self.cursor_offset.lock().unwrap()),
+                        self.modifiers.lock().unwrap().clone(),
+                        self.test_click.clone(),
+                    );
+                }
+            }
+            Message::ArmToggle => {
+                let armed = !self.armed.load(Ordering::Relaxed);
+                self.armed.store(armed, Ordering::Relaxed);
+                if !armed {
+                    self.pending_confirm = false;
+                    stop_clicker(self.is_clicking.clone());
+                }
+                println!("Clicker {}", if armed { "armed" } else { "disarmed" });
+            }
+            Message::SliderChanged(value) => {
+                self.slider_value = value;
+                let delay = match self.speed_mode {
+                    SpeedMode::Milliseconds => clamp_delay_ms(value),
+                    SpeedMode::ClicksPerSecond => cps_to_delay_ms(value),
+                };
+                self.delay_ms.store(delay as usize, Ordering::Relaxed);
+                self.delay_input = delay.to_string();
+                println!("Delay updated to {} ms", delay);
+            }
+            Message::NudgeSpeed(faster) => {
+                let range = self.delay_range;
+                let value = match self.speed_mode {
+                    SpeedMode::Milliseconds if faster => {
+                        self.slider_value.saturating_sub(range.step_ms()).max(range.min_ms())
+                    }
+                    SpeedMode::Milliseconds => {
+                        (self.slider_value + range.step_ms()).min(range.max_ms())
+                    }
+                    SpeedMode::ClicksPerSecond if faster => (self.slider_value + 1).min(MAX_CPS),
+                    SpeedMode::ClicksPerSecond => self.slider_value.saturating_sub(1).max(MIN_CPS),
+                };
+                return self.update(Message::SliderChanged(value));
+            }
+            Message::DelayTextChanged(value) => {
+                if value.is_empty() {
+                    self.delay_input = value;
+                } else if let Ok(parsed) = value.parse::<u32>() {
+                    // Keep the text as typed so intermediate values like "5" on the
+                    // way to "55" aren't rewritten; only the stored delay is clamped.
+                    let delay = clamp_delay_ms(parsed);
+                    self.delay_input = value;
+                    self.delay_ms.store(delay as usize, Ordering::Relaxed);
+                    self.sync_slider();
+                    println!("Delay updated to {} ms", delay);
+                } else {
+                    println!("Ignoring invalid delay input {:?}", value);
+                }
+            }
+            Message::ClickModeChanged(mode) => {
+                {
+                    let mut lock = self.click_mode.lock().unwrap();
+                    *lock = mode;
+                }
+                println!("Click mode changed to {:?}", mode);
+            }
+            Message::ClickTypeChanged(click_type) => {
+                {
+                    let mut lock = self.click_type.lock().unwrap();
+                    *lock = click_type;
+                }
+                println!("Click type changed to {:?}", click_type);
+            }
+            Message::SpeedModeChanged(mode) => {
+                // Keep the stored delay and only re-express it in the new unit.
+                self.speed_mode = mode;
+                self.sync_slider();
+                println!("Speed mode changed to {}", mode);
+            }
+            Message::DelayRangeChanged(range) => {
+                // Only the slider's reach changes; the delay itself is kept.
+                self.delay_range = range;
+                println!("Delay slider range changed to {}", range);
+            }
+            Message::MaxClicksChanged(max) => {
+                self.max_clicks.store(max as usize, Ordering::Relaxed);
+                println!("Max clicks updated to {} (0 = unlimited)", max);
+            }
+            Message::JitterChanged(value) => {
+                self.jitter_ms.store(value as usize, Ordering::Relaxed);
+                println!("Jitter updated to ±{} ms", value);
+            }
+            Message::RangeToggled(enabled) => {
+                self.range_enabled.store(enabled, Ordering::Relaxed);
+                println!("Random range {}", if enabled { "enabled" } else { "disabled" });
+            }
+            // Moving one end past the other drags it along, so min <= max always holds.
+            Message::RangeMinChanged(value) => {
+                let min = clamp_delay_ms(value) as usize;
+                self.range_min_ms.store(min, Ordering::Relaxed);
+                if self.range_max_ms.load(Ordering::Relaxed) < min {
+                    self.range_max_ms.store(min, Ordering::Relaxed);
+                }
+                println!("Random range minimum updated to {} ms", min);
+            }
+            Message::RangeMaxChanged(value) => {
+                let max = clamp_delay_ms(value) as usize;
+                self.range_max_ms.store(max, Ordering::Relaxed);
+                if self.range_min_ms.load(Ordering::Relaxed) > max {
+                    self.range_min_ms.store(max, Ordering::Relaxed);
+                }
+                println!("Random range maximum updated to {} ms", max);
+            }
+            Message::HumanizeToggled(enabled) => {
+                self.humanize_enabled.store(enabled, Ordering::Relaxed);
+                println!("Humanize {}", if enabled { "enabled" } else { "disabled" });
+            }
+            Message::HumanizeStdDevChanged(value) => {
+                self.humanize_std_dev_ms.store(value as usize, Ordering::Relaxed);
+                println!("Humanize std dev updated to {} ms", value);
+            }
+            Message::HoldChanged(value) => {
+                self.hold_ms.store(value as usize, Ordering::Relaxed);
+                println!("Click hold updated to {} ms (0 = tap)", value);
+            }
+            Message::StartDelayChanged(value) => {
+                self.start_delay_ms.store(value as usize, Ordering::Relaxed);
+                println!("Start delay updated to {} ms", value);
+            }
+            Message::JiggleToggled(enabled) => {
+                self.jiggle_enabled.store(enabled, Ordering::Relaxed);
+                println!("Jiggle mode {}", if enabled { "enabled" } else { "disabled" });
+            }
+            Message::JiggleIntervalChanged(value) => {
+                self.jiggle_interval_ms.store(value as usize, Ordering::Relaxed);
+                println!("Jiggle interval updated to {} ms", value);
+            }
+            Message::MaxRunChanged(minutes) => {
+                self.max_run_ms.store(minutes as usize * 60_000, Ordering::Relaxed);
+                println!("Max run time updated to {} min", minutes);
+            }
+            Message::ClickRadiusChanged(value) => {
+                self.click_radius.store(value as usize, Ordering::Relaxed);
+                println!("Click radius updated to {} px", value);
+            }
+            Message::RampToggled(enabled) => {
+                self.ramp_enabled.store(enabled, Ordering::Relaxed);
+                println!("Ramp mode {}", if enabled { "enabled" } else { "disabled" });
+            }
+            Message::RampStartChanged(value) => {
+                self.ramp_start_ms.store(clamp_delay_ms(value) as usize, Ordering::Relaxed);
+                println!("Ramp start delay updated to {} ms", value);
+            }
+            Message::RampEndChanged(value) => {
+                self.ramp_end_ms.store(clamp_delay_ms(value) as usize, Ordering::Relaxed);
+                println!("Ramp end delay updated to {} ms", value);
+            }
+            Message::RampDurationChanged(value) => {
+                self.ramp_duration_ms.store(value as usize, Ordering::Relaxed);
+                println!("Ramp duration updated to {} ms", value);
+            }
+            Message::RampShapeChanged(shape) => {
+                {
+                    let mut lock = self.ramp_shape.lock().unwrap();
+                    *lock = shape;
+                }
+                println!("Ramp shape set to {}", shape);
+            }
+            Message::BurstToggled(enabled) => {
+                self.burst_enabled.store(enabled, Ordering::Relaxed);
+                println!("Burst mode {}", if enabled { "enabled" } else { "disabled" });
+            }
+            Message::BurstSizeChanged(value) => {
+                self.burst_size.store(value as usize, Ordering::Relaxed);
+                println!("Burst size updated to {} clicks", value);
+            }
+            Message::BurstPauseChanged(value) => {
+                self.burst_pause_ms.store(value as usize, Ordering::Relaxed);
+                println!("Burst pause updated to {} ms", value);
+            }
+            Message::ModifierToggled(modifier, enabled) => {
+                {
+                    let mut lock = self.modifiers.lock().unwrap();
+                    set_modifier(&mut lock, modifier, enabled);
+                }
+                println!("{} modifier {}", modifier, if enabled { "enabled" } else { "disabled" });
+            }
+            Message::HotkeyModifierToggled(modifier, enabled) => {
+                {
+                    let mut lock = self.hotkey_modifiers.lock().unwrap();
+                    set_modifier(&mut lock, modifier, enabled);
+                }
+                let hotkey = *self.selected_hotkey.lock().unwrap();
+                println!(
+                    "Start hotkey is now {}",
+                    format_combo(&self.hotkey_modifiers.lock().unwrap(), hotkey)
+                );
+            }
+            Message::ScrollToggled(enabled) => {
+                self.scroll_enabled.store(enabled, Ordering::Relaxed);
+                println!("Scroll mode {}", if enabled { "enabled" } else { "disabled" });
+            }
+            Message::ScrollDirectionChanged(direction) => {
+                {
+                    let mut lock = self.scroll_direction.lock().unwrap();
+                    *lock = direction;
+                }
+                println!("Scroll direction changed to {:?}", direction);
+            }
+            Message::ScrollLinesChanged(value) => {
+                self.scroll_lines.store(value as usize, Ordering::Relaxed);
+                println!("Scroll amount updated to {} lines", value);
+            }
+            Message::DragToggled(enabled) => {
+                self.drag_enabled.store(enabled, Ordering::Relaxed);
+                println!("Drag mode {}", if enabled { "enabled" } else { "disabled" });
+            }
+            Message::SetDragFrom => {
+                let point = DeviceState::new().get_mouse().coords;
+                self.drag.lock().unwrap().from = Some(point);
+                println!("Drag start set to {:?}", point);
+            }
+            Message::SetDragTo => {
+                let point = DeviceState::new().get_mouse().coords;
+                self.drag.lock().unwrap().to = Some(point);
+                println!("Drag end set to {:?}", point);
+            }
+            Message::DragStepsChanged(steps) => {
+                self.drag.lock().unwrap().steps = steps;
+                println!("Drag now moves in {} steps", steps);
+            }
+            Message::KeyPressModeToggled(enabled) => {
+                self.key_press_mode.store(enabled, Ordering::Relaxed);
+                println!("Key press mode {}", if enabled { "enabled" } else { "disabled" });
+            }
+            Message::PressKeyChanged(key) => {
+                self.key_sequence.lock().unwrap().key = key;
+                println!("Press key changed to {}", key);
+            }
+            Message::PressCountChanged(value) => {
+                self.press_count_input = value.chars().filter(char::is_ascii_digit).collect();
+                match self.press_count_input.parse::<u32>() {
+                    Ok(count) if count > 0 => {
+                        self.press_count_invalid = false;
+                        self.key_sequence.lock().unwrap().count = count;
+                        println!("Pressing the key {} times per cycle", count);
+                    }
+                    // The last valid count stays in effect until this is fixed.
+                    _ => self.press_count_invalid = true,
+                }
+            }
+            Message::FinishKeyChanged(key) => {
+                self.key_sequence.lock().unwrap().finish_key = Some(key);
+                println!("Finishing key changed to {}", key);
+            }
+            Message::ClearFinishKey => {
+                self.key_sequence.lock().unwrap().finish_key = None;
+                println!("Finishing key cleared");
+            }
+            Message::RepeatCycleToggled(enabled) => {
+                self.key_sequence.lock().unwrap().repeat = enabled;
+                println!("Key cycle repeat {}", if enabled { "enabled" } else { "disabled" });
+            }
+            Message::ResetStats => {
+                self.total_clicks.store(0, Ordering::Relaxed);
+                self.active_time_us.store(0, Ordering::Relaxed);
+                println!("Session statistics reset.");
+            }
+            Message::TargetXChanged(value) => {
+                self.target_x_input = value;
+                self.apply_target_inputs();
+            }
+            Message::TargetYChanged(value) => {
+                self.target_y_input = value;
+                self.apply_target_inputs();
+            }
+            Message::OffsetToggled(enabled) => {
+                self.offset_enabled.store(enabled, Ordering::Relaxed);
+                println!("Click at cursor offset {}", if enabled { "enabled" } else { "disabled" });
+            }
+            Message::PatternChanged(value) => {
+                match parse_click_pattern(&value) {
+                    Ok(pattern) => {
+                        let conflicting = [
+                            Some(*self.selected_hotkey.lock().unwrap()),
+                            Some(*self.stop_hotkey.lock().unwrap()),
+                            *self.preset_hotkey.lock().unwrap(),
+                        ]
+                        .into_iter()
+                        .flatten()
+                        .find_map(|hotkey| {
+                            pattern
+                                .iter()
+                                .find(|&&button| hotkey.conflicts_with(button))
+                                .map(|&button| (hotkey, button))
+                        });
+                        self.pattern_error = None;
+                        if let Some((hotkey, button)) = conflicting {
+                            self.reject_hotkey_conflict(hotkey, button);
+                        } else {
+                            println!("Click pattern set to {:?}", format_click_pattern(&pattern));
+                            *self.click_pattern.lock().unwrap() = pattern;
+                            self.hotkey_warning = None;
+                        }
+                    }
+                    // Keep clicking the last valid pattern until the input is fixed.
+                    Err(invalid) => self.pattern_error = Some(invalid),
+                }
+                self.pattern_input = value;
+            }
+            Message::OffsetXChanged(value) => {
+                self.offset_x_input = value;
+                self.apply_offset_inputs();
+            }
+            Message::OffsetYChanged(value) => {
+                self.offset_y_input = value;
+                self.apply_offset_inputs();
+            }
+            Message::TargetWindowChanged(value) => {
+                {
+                    let mut lock = self.target_window.lock().unwrap();
+                    *lock = value.clone();
+                }
+                if value.trim().is_empty() {
+                    println!("Target window cleared, clicking in any window.");
+                } else {
+                    println!("Only clicking while {:?} is focused", value.trim());
+                }
+            }
+            Message::CapturePosition => {
+                // Global virtual-desktop coordinates; negative on a monitor left of
+                // or above the primary one.
+                let (x, y) = DeviceState::new().get_mouse().coords;
+                self.target_x_input = x.to_string();
+                self.target_y_input = y.to_string();
+                self.apply_target_inputs();
+            }
+            Message::PickPosition => {
+                // The press on this button has already gone by, so the next
+                // click the listener sees is the one being picked.
+                self.position_pick.store(true, Ordering::Relaxed);
+                println!("Waiting for a click to pick the target position...");
+            }
+            Message::ClearTarget => {
+                self.position_pick.store(false, Ordering::Relaxed);
+                self.target_x_input.clear();
+                self.target_y_input.clear();
+                self.apply_target_inputs();
+            }
+            Message::OverlayToggled(enabled) => {
+                self.show_overlay = enabled;
+                self.sync_overlay();
+                println!("Target overlay {}", if enabled { "enabled" } else { "disabled" });
+            }
+            Message::FocusTargetToggled(enabled) => {
+                self.focus_target_window.store(enabled, Ordering::Relaxed);
+                println!(
+                    "Focusing the target window {}",
+                    if enabled { "enabled" } else { "disabled" }
+                );
+            }
+            Message::ScheduleStartChanged(value) => {
+                self.schedule_start_input = value.chars().filter(char::is_ascii_digit).collect();
+            }
+            Message::ScheduleRunChanged(value) => {
+                self.schedule_run_input = value.chars().filter(char::is_ascii_digit).collect();
+            }
+            Message::ArmSchedule => {
+                let start_in: u64 = self.schedule_start_input.parse().unwrap_or(0);
+                self.scheduled_start = Some(Instant::now() + Duration::from_secs(start_in * 60));
+                self.scheduled_run_minutes = self.schedule_run_input.parse().ok();
+                *self.stop_at.lock().unwrap() = None;
+                println!(
+                    "Scheduled start in {} min, running {}",
+                    start_in,
+                    self.scheduled_run_minutes
+                        .map_or(String::from("until stopped"), |m| format!("for {} min", m))
+                );
+            }
+            Message::CancelSchedule => {
+                self.scheduled_start = None;
+                self.scheduled_run_minutes = None;
+                *self.stop_at.lock().unwrap() = None;
+                println!("Schedule cancelled");
+            }
+            Message::AddRecordedPoint => {
+                let point = DeviceState::new().get_mouse().coords;
+                let mut points = self.recorded_points.lock().unwrap();
+                points.push(SequencePoint::new(point));
+                println!("Recorded point {} at {:?}", points.len(), point);
+            }
+            Message::ClearRecordedPoints => {
+                self.recorded_points.lock().unwrap().clear();
+                println!("Recorded points cleared");
+            }
+            Message::PointClicksChanged(index, clicks) => {
+                if let Some(point) = self.recorded_points.lock().unwrap().get_mut(index) {
+                    point.clicks = clicks;
+                    println!("Point {} now clicked {} times", index + 1, clicks);
+                }
+            }
+            Message::PointDwellChanged(index, dwell_ms) => {
+                if let Some(point) = self.recorded_points.lock().unwrap().get_mut(index) {
+                    point.dwell_ms = dwell_ms;
+                    println!("Point {} now dwells {} ms", index + 1, dwell_ms);
+                }
+            }
+            Message::RemoveRecordedPoint(index) => {
+                let mut points = self.recorded_points.lock().unwrap();
+                if index < points.len() {
+                    let point = points.remove(index);
+                    println!("Removed point {} at {:?}", index + 1, point.pos);
+                }
+            }
+            Message::RecordPath => {
+                self.path_playing.store(false, Ordering::Relaxed);
+                self.recorded_path.lock().unwrap().clear();
+                self.path_recording.store(true, Ordering::Relaxed);
+                println!("Recording cursor path...");
+            }
+            Message::StopPath => {
+                if self.path_recording.swap(false, Ordering::Relaxed) {
+                    let samples = self.recorded_path.lock().unwrap().len();
+                    println!("Recorded cursor path with {} samples", samples);
+                }
+                if self.path_playing.swap(false, Ordering::Relaxed) {
+                    println!("Path playback stopped");
+                }
+            }
+            Message::PlayPath => {
+                self.path_playing.store(true, Ordering::Relaxed);
+                println!("Path playback enabled; it runs while the clicker does");
+            }
+            Message::ClearPath => {
+                self.path_recording.store(false, Ordering::Relaxed);
+                self.path_playing.store(false, Ordering::Relaxed);
+                self.recorded_path.lock().unwrap().clear();
+                println!("Cursor path cleared");
+            }
+            Message::RecordRhythm => {
+                self.rhythm_playing.store(false, Ordering::Relaxed);
+                self.recorded_rhythm.lock().unwrap().clear();
+                self.rhythm_recording.store(true, Ordering::Relaxed);
+                println!("Recording click rhythm...");
+            }
+            Message::StopRhythm => {
+                if self.rhythm_recording.swap(false, Ordering::Relaxed) {
+                    let mut rhythm = self.recorded_rhythm.lock().unwrap();
+                    // The last interval ends with the click on this Stop button.
+                    rhythm.pop();
+                    println!("Recorded click rhythm with {} intervals", rhythm.len());
+                }
+                if self.rhythm_playing.swap(false, Ordering::Relaxed) {
+                    println!("Rhythm replay stopped");
+                }
+            }
+            Message::ReplayRhythm => {
+                self.rhythm_playing.store(true, Ordering::Relaxed);
+                println!("Rhythm replay enabled; it replaces the delay while clicking");
+            }
+            Message::ClearRhythm => {
+                self.rhythm_recording.store(false, Ordering::Relaxed);
+                self.rhythm_playing.store(false, Ordering::Relaxed);
+                self.recorded_rhythm.lock().unwrap().clear();
+                println!("Click rhythm cleared");
+            }
+            Message::Tick => {
+                if self.config_save_due.is_some_and(|due| Instant::now() >= due) {
+                    self.config_save_due = None;
+                    self.save_config();
+                }
+                // Besides handling tray clicks, the tick triggers a redraw so the
+                // readouts pick up values written by the clicker thread.
+                // Lit for one tick after any click, so slow clicking blinks and fast
+                // clicking simply stays lit instead of flickering.
+                let generation = self.click_generation.load(Ordering::Relaxed);
+                self.click_flash = generation != self.seen_click_generation;
+                self.seen_click_generation = generation;
+                // The HTTP API can change the delay behind the GUI's back. A value on
+                // its way to being typed clamps to the stored one, so it's left alone.
+                let delay = self.delay_ms.load(Ordering::Relaxed) as u32;
+                if !self.delay_input.is_empty()
+                    && self.delay_input.parse::<u32>().map(clamp_delay_ms) != Ok(delay)
+                {
+                    self.delay_input = delay.to_string();
+                    self.sync_slider();
+                }
+                while let Some(action) = tray::poll_action() {
+                    match action {
+                        tray::TrayAction::Start => start_clicker(self.is_clicking.clone()),
+                        tray::TrayAction::Stop => stop_clicker(self.is_clicking.clone()),
+                        tray::TrayAction::Quit => return self.update(Message::Quit),
+                    }
+                }
+                for _ in 0..self.preset_requests.swap(0, Ordering::Relaxed) {
+                    self.select_next_preset();
+                }
+                let captured = self.captured_hotkey.lock().unwrap().take();
+                if let Some(CapturedHotkey { capture, hotkey, modifiers }) = captured {
+                    match capture {
+                        HotkeyCapture::Start => {
+                            let _ = self.update(Message::HotkeyChanged(hotkey));
+                            // Only if the key itself was accepted. HotkeyChanged has
+                            // queued a save already, which picks these up too.
+                            if *self.selected_hotkey.lock().unwrap() == hotkey {
+                                *self.hotkey_modifiers.lock().unwrap() = modifiers;
+                            }
+                        }
+                        HotkeyCapture::Stop => {
+                            let _ = self.update(Message::StopHotkeyChanged(hotkey));
+                        }
+                    }
+                }
+                let picked = self.picked_position.lock().unwrap().take();
+                if let Some((x, y)) = picked {
+                    println!("Picked target position ({}, {})", x, y);
+                    self.target_x_input = x.to_string();
+                    self.target_y_input = y.to_string();
+                    self.apply_target_inputs();
+                }
+                let now = Instant::now();
+                if self.scheduled_start.is_some_and(|start| now >= start) {
+                    println!("Scheduled start reached.");
+                    self.scheduled_start = None;
+                    *self.stop_at.lock().unwrap() = self
+                        .scheduled_run_minutes
+                        .take()
+                        .map(|minutes| now + Duration::from_secs(minutes * 60));
+                    start_clicker(self.is_clicking.clone());
+                }
+                // The clicker thread handles this while running; this covers a run
+                // that is paused when the time comes.
+                let stop_due = self.stop_at.lock().unwrap().is_some_and(|stop| now >= stop);
+                if stop_due && !self.is_clicking.is_running() {
+                    println!("Scheduled stop reached.");
+                    *self.stop_at.lock().unwrap() = None;
+                    stop_clicker(self.is_clicking.clone());
+                }
+                let running = self.is_clicking.is_running();
+                if let Some(tray) = &mut self.tray {
+                    tray.update(running);
+                }
+            }
+            Message::ThemeChanged(choice) => {
+                self.theme = choice;
+                println!("Theme changed to {}", choice);
+            }
+            Message::LanguageChanged(language) => {
+                self.language = language;
+                println!("Language changed to {}", language);
+            }
+            Message::SoundsToggled(enabled) => {
+                self.sounds_enabled = enabled;
+                sound::set_enabled(enabled);
+                println!("Sounds {}", if enabled { "enabled" } else { "disabled" });
+            }
+            Message::WindowMoved(x, y) => self.window_position = Some((x, y)),
+            Message::WindowResized(width, height) => self.window_size = Some((width, height)),
+            Message::AlwaysOnTopToggled(enabled) => {
+                self.always_on_top = enabled;
+                println!("Always on top {}", if enabled { "enabled" } else { "disabled" });
+                return iced::window::change_level(iced::window::Id::MAIN, window_level(enabled));
+            }
+            Message::LowPowerToggled(enabled) => {
+                self.low_power = enabled;
+                println!("Low power mode {}", if enabled { "enabled" } else { "disabled" });
+            }
+            Message::CornerFailsafeToggled(enabled) => {
+                self.corner_failsafe.store(enabled, Ordering::Relaxed);
+                println!("Corner failsafe {}", if enabled { "enabled" } else { "disabled" });
+            }
+            Message::PresetSelected(name) => {
+                if let Some(profile) = self.presets.get(&name).cloned() {
+                    self.apply_profile(profile);
+                    self.preset_name_input = name.clone();
+                    println!("Applied preset {:?}", name);
+                    self.selected_preset = Some(name);
+                }
+            }
+            Message::PresetNameChanged(value) => {
+                self.preset_name_input = value;
+            }
+            Message::SavePreset => {
+                let name = self.preset_name_input.trim().to_string();
+                let status = if name.is_empty() {
+                    String::from("Enter a name to save the preset under.")
+                } else if name == DEFAULT_PRESET_NAME {
+                    format!("The {} preset can't be overwritten.", DEFAULT_PRESET_NAME)
+                } else {
+                    self.presets.insert(name.clone(), self.current_profile());
+                    config::save_presets(&self.presets);
+                    self.selected_preset = Some(name.clone());
+                    format!("Saved preset {:?}", name)
+                };
+                println!("{}", status);
+                self.profile_status = Some(status);
+            }
+            Message::DeletePreset => {
+                if let Some(name) = self.selected_preset.take()
+                    && name != DEFAULT_PRESET_NAME
+                {
+                    self.presets.remove(&name);
+                    config::save_presets(&self.presets);
+                    println!("Deleted preset {:?}", name);
+                }
+            }
+            Message::VerboseLoggingToggled(enabled) => {
+                self.verbose_logging = enabled;
+                logging::set_verbose(enabled);
+                println!("Verbose click logging {}", if enabled { "enabled" } else { "disabled" });
+            }
+            Message::ExportProfile => {
+                let path = rfd::FileDialog::new()
+                    .add_filter("Click profile", &["json"])
+                    .set_file_name("profile.json")
+                    .save_file();
+                if let Some(path) = path {
+                    let status = match self.current_profile().save_to(&path) {
+                        Ok(()) => format!("Exported profile to {}", path.display()),
+                        Err(e) => format!("Failed to export profile: {}", e),
+                    };
+                    println!("{}", status);
+                    self.profile_status = Some(status);
+                }
+            }
+            Message::ImportProfile => {
+                let path = rfd::FileDialog::new()
+                    .add_filter("Click profile", &["json"])
+                    .pick_file();
+                if let Some(path) = path {
+                    let status = match Profile::load_from(&path) {
+                        Ok(profile) => {
+                            self.apply_profile(profile);
+                            format!("Imported profile from {}", path.display())
+                        }
+                        Err(e) => format!("Failed to import profile: {}", e),
+                    };
+                    println!("{}", status);
+                    self.profile_status = Some(status);
+                }
+            }
+            Message::Quit => {
+                stop_clicker(self.is_clicking.clone());
+                self.save_config();
+                // `main` shuts the worker threads down once the window is gone.
+                return iced::window::close(iced::window::Id::MAIN);
+            }
+            Message::HotkeyChanged(hotkey) => {
+                if let Some(button) = self.clicked_button_conflict(hotkey) {
+                    self.reject_hotkey_conflict(hotkey, button);
+                } else {
+                    {
+                        let mut lock = self.selected_hotkey.lock().unwrap();
+                        *lock = hotkey;
+                    }
+                    self.hotkey_warning = None;
+                    println!("Hotkey changed to {:?}", hotkey);
+                }
+            }
+            Message::StopHotkeyChanged(hotkey) => {
+                if let Some(button) = self.clicked_button_conflict(hotkey) {
+                    self.reject_hotkey_conflict(hotkey, button);
+                } else {
+                    {
+                        let mut lock = self.stop_hotkey.lock().unwrap();
+                        *lock = hotkey;
+                    }
+                    self.hotkey_warning = None;
+                    println!("Stop hotkey changed to {:?}", hotkey);
+                }
+            }
+            Message::PresetHotkeyChanged(hotkey) => {
+                let start = *self.selected_hotkey.lock().unwrap();
+                let stop = *self.stop_hotkey.lock().unwrap();
+                if let Some(button) = self.clicked_button_conflict(hotkey) {
+                    self.reject_hotkey_conflict(hotkey, button);
+                } else if hotkey == start || hotkey == stop {
+                    let warning = format!("{} is already the start/stop hotkey.", hotkey);
+                    println!("{}", warning);
+                    self.hotkey_warning = Some(warning);
+                } else {
+                    {
+                        let mut lock = self.preset_hotkey.lock().unwrap();
+                        *lock = Some(hotkey);
+                    }
+                    self.hotkey_warning = None;
+                    println!("Next preset hotkey changed to {:?}", hotkey);
+                }
+            }
+            Message::CaptureHotkey(capture) => {
+                *self.hotkey_capture.lock().unwrap() = Some(capture);
+                println!("Waiting for a key press for the {:?} hotkey...", capture);
+            }
+            Message::ClearPresetHotkey => {
+                {
+                    let mut lock = self.preset_hotkey.lock().unwrap();
+                    *lock = None;
+                }
+                println!("Next preset hotkey cleared");
+            }
+            Message::MouseButtonChanged(button) => {
+                let conflicting = [
+                    Some(*self.selected_hotkey.lock().unwrap()),
+                    Some(*self.stop_hotkey.lock().unwrap()),
+                    *self.preset_hotkey.lock().unwrap(),
+                ]
+                .into_iter()
+                .flatten()
+                .find(|hotkey| hotkey.conflicts_with(button));
+
+                if let Some(hotkey) = conflicting {
+                    self.reject_hotkey_conflict(hotkey, button);
+                } else {
+                    {
+                        let mut lock = self.mouse_button.lock().unwrap();
+                        *lock = button;
+                    }
+                    self.hotkey_warning = None;
+                    println!("Mouse button changed to {:?}", button);
+                }
+            }
+        }
+
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Self::Message> {
+        // Poll quickly while clicking for the live readouts; when stopped only the
+        // running/paused state can change, so a slow tick is enough.
+        let interval = Duration::from_millis(self.tick_interval_ms());
+        let tick = iced::time::every(interval).map(|_| Message::Tick);
+        let window_events = iced::event::listen_with(|event, status| match event {
+            iced::Event::Window(_, iced::window::Event::CloseRequested) => Some(Message::Quit),
+            iced::Event::Window(_, iced::window::Event::Moved { x, y }) => {
+                Some(Message::WindowMoved(x, y))
+            }
+            iced::Event::Window(_, iced::window::Event::Resized { width, height }) => {
+                Some(Message::WindowResized(width, height))
+            }
+            // Only keys no widget used, so typing "-" into a text box doesn't
+            // also change the speed.
+            iced::Event::Keyboard(iced::keyboard::Event::KeyPressed { key, .. })
+                if status == iced::event::Status::Ignored =>
+            {
+                match key.as_ref() {
+                    iced::keyboard::Key::Character("+" | "=") => Some(Message::NudgeSpeed(true)),
+                    iced::keyboard::Key::Character("-") => Some(Message::NudgeSpeed(false)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        });
+        Subscription::batch([tick, window_events])
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let lang = self.language;
+        let armed = self.armed.load(Ordering::Relaxed);
+        let countdown = self
+            .start_deadline
+            .lock()
+            .unwrap()
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()));
+        let label = match countdown {
+            Some(remaining) if self.is_clicking.is_running() => {
+                let remaining = format!("{:.1}", remaining.as_secs_f64());
+                lang.fill(Label::StatusStartingIn, &[&remaining])
+            }
+            _ if self.is_clicking.is_running() => String::from(lang.get(Label::StatusRunning)),
+            _ if self.is_clicking.is_paused() => String::from(lang.get(Label::StatusPaused)),
+            _ if !armed => String::from(lang.get(Label::StatusDisarmed)),
+            _ => String::from(lang.get(Label::StatusStopped)),
+        };
+
+        let input_error = self.input_error.lock().unwrap().clone();
+        let start_blocked = input_error.is_some() || self.pending_confirm || !armed;
+        let [start_button, pause_button, stop_button] =
+            control_buttons(self.is_clicking.state(), start_blocked)
+                .map(|(label, message)| action_button(lang.get(label), message));
+
+        let hotkey_picklist = PickList::new(
+            &Hotkey::ALL[..],
+            Some(*self.selected_hotkey.lock().unwrap()),
+            Message::HotkeyChanged,
+        )
+        .placeholder(lang.get(Label::SelectHotkey));
+
+        // Hold mode stops on release, so a separate stop key does nothing there.
+        // PickList can't be disabled, so it's shown as a greyed-out button instead.
+        let stop_hotkey = *self.stop_hotkey.lock().unwrap();
+        let stop_hotkey_split = *self.click_mode.lock().unwrap() == ClickMode::Toggle;
+        let stop_hotkey_picklist: Element<'_, Message> = if stop_hotkey_split {
+            PickList::new(&Hotkey::ALL[..], Some(stop_hotkey), Message::StopHotkeyChanged)
+                .placeholder(lang.get(Label::SelectStopHotkey))
+                .into()
+        } else {
+            button(text(stop_hotkey.to_string())).style(theme::Button::Secondary).into()
+        };
+
+        let preset_hotkey_picklist = PickList::new(
+            &Hotkey::ALL[..],
+            *self.preset_hotkey.lock().unwrap(),
+            Message::PresetHotkeyChanged,
+        )
+        .placeholder(lang.get(Label::NoHotkey));
+
+        let hotkey_row = row![
+            text(lang.get(Label::StartHotkey)),
+            hotkey_picklist,
+            button(lang.get(Label::SetHotkey))
+                .on_press(Message::CaptureHotkey(HotkeyCapture::Start)),
+            text(lang.get(Label::StopHotkey)),
+            stop_hotkey_picklist,
+            button(lang.get(Label::SetHotkey)).on_press_maybe(
+                stop_hotkey_split.then_some(Message::CaptureHotkey(HotkeyCapture::Stop))
+            ),
+            text(lang.get(Label::NextPresetHotkey)),
+            preset_hotkey_picklist,
+            button(lang.get(Label::Clear)).on_press(Message::ClearPresetHotkey),
+        ]
+        .spacing(20);
+
+        let mouse_button_picklist = PickList::new(
+            &MouseButtonChoice::ALL[..],
+            Some(*self.mouse_button.lock().unwrap()),
+            Message::MouseButtonChanged,
+        )
+        .placeholder(lang.get(Label::SelectMouseButton));
+
+        let click_mode_picklist = PickList::new(
+            &ClickMode::ALL[..],
+            Some(*self.click_mode.lock().unwrap()),
+            Message::ClickModeChanged,
+        )
+        .placeholder(lang.get(Label::SelectClickMode));
+
+        let click_type_picklist = PickList::new(
+            &ClickType::ALL[..],
+            Some(*self.click_type.lock().unwrap()),
+            Message::ClickTypeChanged,
+        )
+        .placeholder(lang.get(Label::SelectClickType));
+
+        let theme_picklist =
+            PickList::new(&ThemeChoice::ALL[..], Some(self.theme), Message::ThemeChanged);
+
+        let language_picklist =
+            PickList::new(&Language::ALL[..], Some(lang), Message::LanguageChanged)
+                .placeholder(lang.get(Label::SelectLanguage));
+
+        let picklist_row = row![
+            mouse_button_picklist,
+            click_type_picklist,
+            click_mode_picklist,
+            theme_picklist,
+            language_picklist,
+        ]
+        .spacing(20);
+
+        let speed_mode_picklist = PickList::new(
+            &SpeedMode::ALL[..],
+            Some(self.speed_mode),
+            Message::SpeedModeChanged,
+        );
+
+        let (speed_label, speed_slider) = match self.speed_mode {
+            SpeedMode::Milliseconds => (
+                lang.fill(Label::SpeedDelay, &[&format_delay(self.slider_value)]),
+                slider(
+                    self.delay_range.min_ms()..=self.delay_range.max_ms(),
+                    self.slider_value,
+                    Message::SliderChanged,
+                )
+                .step(self.delay_range.step_ms()),
+            ),
+            SpeedMode::ClicksPerSecond => (
+                {
+                    let delay_ms = cps_to_delay_ms(self.slider_value);
+                    lang.fill(Label::SpeedCps, &[&self.slider_value, &delay_ms])
+                },
+                slider(MIN_CPS..=MAX_CPS, self.slider_value, Message::SliderChanged).step(1u32),
+            ),
+        };
+
+        let delay_text_input = text_input("ms", &self.delay_input)
+            .on_input(Message::DelayTextChanged)
+            .width(Length::Fixed(80.0));
+
+        let delay_range_picklist = (self.speed_mode == SpeedMode::Milliseconds).then(|| {
+            PickList::new(&DelayRange::ALL[..], Some(self.delay_range), Message::DelayRangeChanged)
+        });
+        let speed_row = row![text(speed_label), speed_mode_picklist, delay_text_input, text("ms")]
+            .push_maybe(delay_range_picklist)
+            .spacing(20);
+
+        // Jitter, humanize and the click itself all stretch the real interval, so show
+        // what the loop actually achieved next to what was asked for.
+        let actual_interval_us = self.actual_interval_us.load(Ordering::Relaxed);
+        let configured_ms = self.delay_ms.load(Ordering::Relaxed);
+        let interval_label = if actual_interval_us == 0 {
+            lang.fill(Label::IntervalNoData, &[&configured_ms])
+        } else {
+            let actual_ms = (actual_interval_us + 500) / 1000;
+            let jitter_us = self.interval_jitter_us.load(Ordering::Relaxed);
+            let jitter_ms = format!("{:.1}", jitter_us as f64 / 1000.0);
+            lang.fill(Label::Interval, &[&configured_ms, &actual_ms, &jitter_ms])
+        };
+
+        let jitter_value = self.jitter_ms.load(Ordering::Relaxed) as u32;
+        let humanize_enabled = self.humanize_enabled.load(Ordering::Relaxed);
+        let humanize_std_dev = self.humanize_std_dev_ms.load(Ordering::Relaxed) as u32;
+        let range_min = self.range_min_ms.load(Ordering::Relaxed) as u32;
+        let range_max = self.range_max_ms.load(Ordering::Relaxed) as u32;
+        let jitter_label = if humanize_enabled {
+            lang.fill(Label::JitterIgnored, &[&jitter_value])
+        } else {
+            lang.fill(Label::Jitter, &[&jitter_value])
+        };
+        let start_delay_value = self.start_delay_ms.load(Ordering::Relaxed) as u32;
+        let hold_value = self.hold_ms.load(Ordering::Relaxed) as u32;
+        let hold_label = if hold_value == 0 {
+            String::from(lang.get(Label::HoldTap))
+        } else {
+            lang.fill(Label::Hold, &[&hold_value])
+        };
+
+        let max_clicks = self.max_clicks.load(Ordering::Relaxed);
+        let max_clicks_value = if max_clicks == 0 {
+            String::new()
+        } else {
+            max_clicks.to_string()
+        };
+        let max_clicks_input = text_input(lang.get(Label::Unlimited), &max_clicks_value)
+            .on_input(|value| Message::MaxClicksChanged(digits_value(&value)))
+            .width(Length::Fixed(120.0));
+
+        let done = self.clicks_done.load(Ordering::Relaxed);
+        // A paused run still has its count, so it shows progress as well.
+        let in_session = self.is_clicking.state() != ClickerState::Stopped;
+        let remaining_label = if max_clicks == 0 {
+            String::from(lang.get(Label::RemainingUnlimited))
+        } else if in_session {
+            lang.fill(Label::ClickedOf, &[&done.min(max_clicks), &max_clicks])
+        } else {
+            lang.fill(Label::Remaining, &[&max_clicks.saturating_sub(done)])
+        };
+        // `clicks_done` is left at the limit after the auto-stop, so the bar stays
+        // full until the next run starts.
+        let max_clicks_progress = (max_clicks > 0).then(|| {
+            progress_bar(0.0..=max_clicks as f32, done.min(max_clicks) as f32)
+                .height(Length::Fixed(10.0))
+        });
+
+        let max_clicks_section = column![
+            row![text(lang.get(Label::MaxClicks)), max_clicks_input, text(remaining_label)]
+                .spacing(20),
+        ]
+        .push_maybe(max_clicks_progress)
+        .spacing(5);
+
+        let max_run_minutes = (self.max_run_ms.load(Ordering::Relaxed) / 60_000) as u32;
+        let max_run_label = if max_run_minutes == 0 {
+            String::from(lang.get(Label::MaxRunUnlimited))
+        } else {
+            lang.fill(Label::MaxRun, &[&max_run_minutes])
+        };
+        let max_run_row = row![
+            text(max_run_label),
+            slider(0..=MAX_RUN_MINUTES, max_run_minutes, Message::MaxRunChanged).step(5u32),
+        ]
+        .spacing(20);
+
+        let start_modifiers = self.hotkey_modifiers.lock().unwrap().clone();
+        let start_combo = format_combo(&start_modifiers, *self.selected_hotkey.lock().unwrap());
+        let combo_row = Modifier::ALL
+            .iter()
+            .fold(row![text(lang.get(Label::StartCombo))], |row, &modifier| {
+                row.push(
+                    checkbox(modifier.to_string(), start_modifiers.contains(&modifier)).on_toggle(
+                        move |enabled| Message::HotkeyModifierToggled(modifier, enabled),
+                    ),
+                )
+            })
+            .push(text(start_combo))
+            .spacing(20);
+
+        let capture_label = match *self.hotkey_capture.lock().unwrap() {
+            Some(HotkeyCapture::Start) => Some(text(lang.get(Label::CapturingStartHotkey))),
+            Some(HotkeyCapture::Stop) => Some(text(lang.get(Label::CapturingStopHotkey))),
+            None => None,
+        };
+        let hotkey_section = column![
+            hotkey_row,
+            combo_row,
+            text(lang.get(Label::PanicKeyHint)),
+            checkbox(lang.get(Label::CornerFailsafe), self.corner_failsafe.load(Ordering::Relaxed))
+                .on_toggle(Message::CornerFailsafeToggled),
+        ]
+            .push_maybe(capture_label)
+            .push_maybe(
+                self.hotkey_warning
+                    .as_deref()
+                    .map(|warning| text(warning).style(theme::Text::Color(WARNING_COLOR))),
+            )
+            .spacing(10);
+
+        let target_label = match *self.target_position.lock().unwrap() {
+            Some((x, y)) => lang.fill(Label::Target, &[&x, &y]),
+            None => String::from(lang.get(Label::TargetCursor)),
+        };
+        let picking = self.position_pick.load(Ordering::Relaxed);
+        let pick_button = button(lang.get(if picking {
+            Label::PickingPosition
+        } else {
+            Label::PickPosition
+        }))
+        .on_press_maybe((!picking).then_some(Message::PickPosition));
+        let target_row = row![
+            text(lang.get(Label::ClickAtX)),
+            text_input(lang.get(Label::CursorPlaceholder), &self.target_x_input)
+                .on_input(Message::TargetXChanged)
+                .width(Length::Fixed(80.0)),
+            text("Y:"),
+            text_input(lang.get(Label::CursorPlaceholder), &self.target_y_input)
+                .on_input(Message::TargetYChanged)
+                .width(Length::Fixed(80.0)),
+            button(lang.get(Label::CapturePosition)).on_press(Message::CapturePosition),
+            pick_button,
+            button(lang.get(Label::Clear)).on_press(Message::ClearTarget),
+            text(target_label),
+        ]
+        .spacing(10);
+
+        let click_radius = self.click_radius.load(Ordering::Relaxed) as u32;
+        let radius_row = row![
+            text(lang.fill(Label::RandomRadius, &[&click_radius])),
+            slider(0..=MAX_CLICK_RADIUS_PX, click_radius, Message::ClickRadiusChanged),
+        ]
+        .spacing(20);
+
+        let pattern_row = row![
+            text(lang.get(Label::ClickPattern)),
+            text_input("LLR", &self.pattern_input)
+                .on_input(Message::PatternChanged)
+                .width(Length::Fixed(120.0)),
+        ]
+        .push_maybe(self.pattern_error.map(|invalid| {
+            text(lang.fill(Label::PatternInvalid, &[&format!("{:?}", invalid)]))
+                .style(theme::Text::Color(WARNING_COLOR))
+        }))
+        .spacing(10);
+
+        let offset_row = row![
+            checkbox(lang.get(Label::CursorOffset), self.offset_enabled.load(Ordering::Relaxed))
+            .on_toggle(Message::OffsetToggled),
+            text("X:"),
+            text_input("0", &self.offset_x_input)
+                .on_input(Message::OffsetXChanged)
+                .width(Length::Fixed(60.0)),
+            text("Y:"),
+            text_input("0", &self.offset_y_input)
+                .on_input(Message::OffsetYChanged)
+                .width(Length::Fixed(60.0)),
+        ]
+        .spacing(10);
+
+        // Only applies when the cursor is moved for the click, never at the cursor itself.
+        let focus_checkbox = checkbox(
+            if focus::SUPPORTED {
+                lang.get(Label::FocusTarget)
+            } else {
+                lang.get(Label::FocusTargetWindowsOnly)
+            },
+            self.focus_target_window.load(Ordering::Relaxed),
+        );
+        let focus_checkbox = if focus::SUPPORTED {
+            focus_checkbox.on_toggle(Message::FocusTargetToggled)
+        } else {
+            focus_checkbox
+        };
+
+        let overlay_checkbox = checkbox(
+            if overlay::SUPPORTED {
+                lang.get(Label::TargetOverlay)
+            } else {
+                lang.get(Label::TargetOverlayWindowsOnly)
+            },
+            self.show_overlay,
+        );
+        let overlay_checkbox = if overlay::SUPPORTED {
+            overlay_checkbox.on_toggle(Message::OverlayToggled)
+        } else {
+            overlay_checkbox
+        };
+
+        let points = self.recorded_points.lock().unwrap().clone();
+        let points_summary = if points.is_empty() {
+            String::from(lang.get(Label::SequenceNone))
+        } else {
+            lang.fill(Label::Sequence, &[&points.len()])
+        };
+        let points_list = points.iter().enumerate().fold(column![], |list, (i, point)| {
+            let (x, y) = point.pos;
+            list.push(
+                row![
+                    text(format!("{}: ({}, {})", i + 1, x, y)),
+                    text(lang.get(Label::PointClicks)),
+                    text_input("1", &point.clicks.to_string())
+                        .on_input(move |value| Message::PointClicksChanged(i, digits_value(&value)))
+                        .width(Length::Fixed(50.0)),
+                    text(lang.get(Label::PointDwell)),
+                    text_input("0", &point.dwell_ms.to_string())
+                        .on_input(move |value| Message::PointDwellChanged(i, digits_value(&value)))
+                        .width(Length::Fixed(70.0)),
+                    text("ms"),
+                    button(lang.get(Label::Remove)).on_press(Message::RemoveRecordedPoint(i)),
+                ]
+                .spacing(10),
+            )
+        });
+        let target_window = self.target_window.lock().unwrap().clone();
+        let target_window_row = row![
+            text(lang.get(Label::OnlyInWindow)),
+            text_input(lang.get(Label::AnyWindow), &target_window)
+                .on_input(Message::TargetWindowChanged)
+                .width(Length::Fixed(200.0)),
+        ]
+        .spacing(10);
+
+        let now = Instant::now();
+        let stop_time = *self.stop_at.lock().unwrap();
+        let schedule_status = match (self.scheduled_start, stop_time) {
+            (Some(start), _) => {
+                let countdown = format_countdown(start.saturating_duration_since(now));
+                lang.fill(Label::StartsIn, &[&countdown])
+            }
+            (None, Some(stop)) => {
+                let countdown = format_countdown(stop.saturating_duration_since(now));
+                lang.fill(Label::StopsIn, &[&countdown])
+            }
+            (None, None) => String::from(lang.get(Label::NoSchedule)),
+        };
+        let has_schedule = self.scheduled_start.is_some() || stop_time.is_some();
+        let cancel_schedule_button = action_button(
+            lang.get(Label::CancelSchedule),
+            has_schedule.then_some(Message::CancelSchedule),
+        );
+        let schedule_row = row![
+            text(lang.get(Label::StartInMinutes)),
+            text_input("0", &self.schedule_start_input)
+                .on_input(Message::ScheduleStartChanged)
+                .width(Length::Fixed(60.0)),
+            text(lang.get(Label::RunForMinutes)),
+            text_input(lang.get(Label::UntilStopped), &self.schedule_run_input)
+                .on_input(Message::ScheduleRunChanged)
+                .width(Length::Fixed(110.0)),
+            button(lang.get(Label::Schedule)).on_press(Message::ArmSchedule),
+            cancel_schedule_button,
+            text(schedule_status),
+        ]
+        .spacing(10);
+
+        let sequence_section = column![
+            row![
+                text(points_summary),
+                button(lang.get(Label::AddCurrentPosition)).on_press(Message::AddRecordedPoint),
+                button(lang.get(Label::Clear)).on_press(Message::ClearRecordedPoints),
+            ]
+            .spacing(10),
+            points_list.spacing(5),
+        ]
+        .spacing(10);
+
+        let path_recording = self.path_recording.load(Ordering::Relaxed);
+        let path_playing = self.path_playing.load(Ordering::Relaxed);
+        let (path_samples, path_length) = {
+            let path = self.recorded_path.lock().unwrap();
+            (path.len(), path.iter().map(|&(_, _, elapsed)| elapsed).sum::<Duration>())
+        };
+        let path_summary = if path_recording {
+            lang.fill(Label::PathRecording, &[&path_samples])
+        } else if path_samples == 0 {
+            String::from(lang.get(Label::PathNone))
+        } else {
+            let seconds = format!("{:.1}", path_length.as_secs_f64());
+            let summary = lang.fill(Label::Path, &[&path_samples, &seconds]);
+            if path_playing {
+                summary + lang.get(Label::PathReplayed)
+            } else {
+                summary
+            }
+        };
+        let record_path_button = action_button(
+            lang.get(Label::RecordPath),
+            (!path_recording).then_some(Message::RecordPath),
+        );
+        let stop_path_button = action_button(
+            lang.get(Label::Stop),
+            (path_recording || path_playing).then_some(Message::StopPath),
+        );
+        let play_path_button = action_button(
+            lang.get(Label::Play),
+            (!path_recording && !path_playing && path_samples > 0).then_some(Message::PlayPath),
+        );
+        let path_row = row![
+            text(path_summary),
+            record_path_button,
+            stop_path_button,
+            play_path_button,
+            button(lang.get(Label::Clear)).on_press(Message::ClearPath),
+        ]
+        .spacing(10);
+
+        let rhythm_recording = self.rhythm_recording.load(Ordering::Relaxed);
+        let rhythm_playing = self.rhythm_playing.load(Ordering::Relaxed);
+        let (rhythm_intervals, rhythm_length) = {
+            let rhythm = self.recorded_rhythm.lock().unwrap();
+            (rhythm.len(), rhythm.iter().sum::<Duration>())
+        };
+        let rhythm_summary = if rhythm_recording {
+            lang.fill(Label::RhythmRecording, &[&rhythm_intervals])
+        } else if rhythm_intervals == 0 {
+            String::from(lang.get(Label::RhythmNone))
+        } else {
+            let seconds = format!("{:.1}", rhythm_length.as_secs_f64());
+            let summary = lang.fill(Label::Rhythm, &[&rhythm_intervals, &seconds]);
+            if rhythm_playing {
+                summary + lang.get(Label::RhythmReplayed)
+            } else {
+                summary
+            }
+        };
+        let rhythm_row = row![
+            text(rhythm_summary),
+            action_button(
+                lang.get(Label::RecordRhythm),
+                (!rhythm_recording).then_some(Message::RecordRhythm),
+            ),
+            action_button(
+                lang.get(Label::Stop),
+                (rhythm_recording || rhythm_playing).then_some(Message::StopRhythm),
+            ),
+            action_button(
+                lang.get(Label::Replay),
+                (!rhythm_recording && !rhythm_playing && rhythm_intervals > 0)
+                    .then_some(Message::ReplayRhythm),
+            ),
+            button(lang.get(Label::Clear)).on_press(Message::ClearRhythm),
+        ]
+        .spacing(10);
+
+        let preset_names = self.preset_names();
+        let can_delete = self
+            .selected_preset
+            .as_deref()
+            .is_some_and(|name| name != DEFAULT_PRESET_NAME);
+        let delete_button =
+            action_button(lang.get(Label::Delete), can_delete.then_some(Message::DeletePreset));
+        let preset_row = row![
+            PickList::new(preset_names, self.selected_preset.clone(), Message::PresetSelected)
+                .placeholder(lang.get(Label::SelectPreset)),
+            text_input(lang.get(Label::PresetName), &self.preset_name_input)
+                .on_input(Message::PresetNameChanged)
+                .width(Length::Fixed(150.0)),
+            button(lang.get(Label::SaveAs)).on_press(Message::SavePreset),
+            delete_button,
+        ]
+        .spacing(10);
+
+        let profile_section = column![
+            preset_row,
+            row![
+                button(lang.get(Label::ExportProfile)).on_press(Message::ExportProfile),
+                button(lang.get(Label::ImportProfile)).on_press(Message::ImportProfile),
+            ]
+            .spacing(20),
+        ]
+        .push_maybe(self.profile_status.as_deref().map(text))
+        .spacing(10);
+
+        let jiggle_interval = self.jiggle_interval_ms.load(Ordering::Relaxed) as u32;
+        let jiggle_row = row![
+            checkbox(lang.get(Label::Jiggle), self.jiggle_enabled.load(Ordering::Relaxed))
+                .on_toggle(Message::JiggleToggled),
+            text(lang.fill(
+                Label::JiggleEvery,
+                &[&format!("{:.1}", jiggle_interval as f64 / 1000.0)]
+            )),
+            slider(1000..=MAX_JIGGLE_INTERVAL_MS, jiggle_interval, Message::JiggleIntervalChanged)
+                .step(1000u32),
+        ]
+        .spacing(20);
+
+        let key_sequence = *self.key_sequence.lock().unwrap();
+        let key_press_row = row![
+            checkbox(lang.get(Label::KeyPress), self.key_press_mode.load(Ordering::Relaxed))
+                .on_toggle(Message::KeyPressModeToggled),
+            PickList::new(&PressKey::ALL[..], Some(key_sequence.key), Message::PressKeyChanged)
+                .placeholder(lang.get(Label::SelectKey)),
+            text_input("1", &self.press_count_input)
+                .on_input(Message::PressCountChanged)
+                .width(Length::Fixed(60.0)),
+            text(lang.get(Label::PressCount)),
+            PickList::new(&PressKey::ALL[..], key_sequence.finish_key, Message::FinishKeyChanged)
+                .placeholder(lang.get(Label::NoFinishKey)),
+            button(lang.get(Label::Clear)).on_press(Message::ClearFinishKey),
+            checkbox(lang.get(Label::RepeatCycle), key_sequence.repeat)
+                .on_toggle(Message::RepeatCycleToggled),
+        ]
+        .push_maybe(self.press_count_invalid.then(|| {
+            text(lang.get(Label::PressCountInvalid)).style(theme::Text::Color(WARNING_COLOR))
+        }))
+        .spacing(20);
+
+        let burst_size = self.burst_size.load(Ordering::Relaxed) as u32;
+        let burst_pause = self.burst_pause_ms.load(Ordering::Relaxed) as u32;
+        let ramp_start = self.ramp_start_ms.load(Ordering::Relaxed) as u32;
+        let ramp_end = self.ramp_end_ms.load(Ordering::Relaxed) as u32;
+        let ramp_duration = self.ramp_duration_ms.load(Ordering::Relaxed) as u32;
+        let ramp_enabled = self.ramp_enabled.load(Ordering::Relaxed);
+        let ramp_shape = *self.ramp_shape.lock().unwrap();
+        let ramp_duration_label = match ramp_shape {
+            RampShape::Linear => Label::RampOver,
+            RampShape::Alternating => Label::RampSwapEvery,
+        };
+        // Only meaningful once the clicker has worked one out for this run.
+        let ramp_current = (ramp_enabled && self.is_clicking.is_running()).then(|| {
+            let delay = self.ramp_delay_ms.load(Ordering::Relaxed);
+            text(lang.fill(Label::RampCurrent, &[&delay]))
+        });
+        let ramp_section = column![
+            row![
+                checkbox(lang.get(Label::Ramp), ramp_enabled).on_toggle(Message::RampToggled),
+                PickList::new(&RampShape::ALL[..], Some(ramp_shape), Message::RampShapeChanged),
+            ]
+            .spacing(20),
+            text(lang.fill(Label::RampFrom, &[&ramp_start])),
+            slider(MIN_DELAY_MS..=MAX_RAMP_DELAY_MS, ramp_start, Message::RampStartChanged)
+                .step(10u32),
+            text(lang.fill(Label::RampTo, &[&ramp_end])),
+            slider(MIN_DELAY_MS..=MAX_RAMP_DELAY_MS, ramp_end, Message::RampEndChanged)
+                .step(10u32),
+            text(lang.fill(
+                ramp_duration_label,
+                &[&format!("{:.0}", ramp_duration as f64 / 1000.0)]
+            )),
+            slider(1000..=MAX_RAMP_DURATION_MS, ramp_duration, Message::RampDurationChanged)
+                .step(1000u32),
+        ]
+        .push_maybe(ramp_current)
+        .spacing(10);
+
+        let burst_section = column![
+            checkbox(lang.get(Label::BurstMode), self.burst_enabled.load(Ordering::Relaxed))
+                .on_toggle(Message::BurstToggled),
+            text(lang.fill(Label::BurstSize, &[&burst_size])),
+            slider(1..=MAX_BURST_SIZE, burst_size, Message::BurstSizeChanged),
+            text(lang.fill(
+                Label::BurstPause,
+                &[&format!("{:.1}", burst_pause as f64 / 1000.0)]
+            )),
+            slider(0..=MAX_BURST_PAUSE_MS, burst_pause, Message::BurstPauseChanged).step(100u32),
+        ]
+        .spacing(10);
+
+        let held_modifiers = self.modifiers.lock().unwrap().clone();
+        let modifier_row = Modifier::ALL
+            .iter()
+            .fold(row![text(lang.get(Label::HoldModifiers))], |row, &modifier| {
+                row.push(
+                    checkbox(modifier.to_string(), held_modifiers.contains(&modifier))
+                        .on_toggle(move |enabled| Message::ModifierToggled(modifier, enabled)),
+                )
+            })
+            .spacing(20);
+
+        let scroll_lines = self.scroll_lines.load(Ordering::Relaxed) as u32;
+        let scroll_row = row![
+            checkbox(lang.get(Label::Scroll), self.scroll_enabled.load(Ordering::Relaxed))
+                .on_toggle(Message::ScrollToggled),
+            PickList::new(
+                &ScrollDirection::ALL[..],
+                Some(*self.scroll_direction.lock().unwrap()),
+                Message::ScrollDirectionChanged,
+            ),
+            text(lang.fill(Label::ScrollLines, &[&scroll_lines])),
+            slider(1..=MAX_SCROLL_LINES, scroll_lines, Message::ScrollLinesChanged),
+        ]
+        .spacing(20);
+
+        let drag = *self.drag.lock().unwrap();
+        let drag_from_label = match drag.from {
+            Some((x, y)) => lang.fill(Label::DragFrom, &[&x, &y]),
+            None => String::from(lang.get(Label::DragFromUnset)),
+        };
+        let drag_to_label = match drag.to {
+            Some((x, y)) => lang.fill(Label::DragTo, &[&x, &y]),
+            None => String::from(lang.get(Label::DragToUnset)),
+        };
+        let drag_row = row![
+            checkbox(lang.get(Label::DragMode), self.drag_enabled.load(Ordering::Relaxed))
+                .on_toggle(Message::DragToggled),
+            text(drag_from_label),
+            button(lang.get(Label::SetDragFrom)).on_press(Message::SetDragFrom),
+            text(drag_to_label),
+            button(lang.get(Label::SetDragTo)).on_press(Message::SetDragTo),
+            text(lang.fill(Label::DragSteps, &[&drag.steps])),
+            slider(0..=MAX_DRAG_STEPS, drag.steps, Message::DragStepsChanged),
+        ]
+        .spacing(20);
+
+        let arm_label = if armed { Label::Disarm } else { Label::Arm };
+        let start_stop_row = row![
+            start_button,
+            pause_button,
+            stop_button,
+            button(lang.get(arm_label)).on_press(Message::ArmToggle),
+            button(lang.get(Label::TestClick)).on_press(Message::TestClick),
+            checkbox(lang.get(Label::SoundsOnStartStop), self.sounds_enabled)
+                .on_toggle(Message::SoundsToggled),
+            checkbox(lang.get(Label::VerboseLogging), self.verbose_logging)
+                .on_toggle(Message::VerboseLoggingToggled),
+            checkbox(lang.get(Label::AlwaysOnTop), self.always_on_top)
+                .on_toggle(Message::AlwaysOnTopToggled),
+        ]
+        .spacing(20);
+
+        let confirm_row = if self.pending_confirm && !self.is_clicking.is_running() {
+            Some(
+                row![
+                    text(lang.fill(Label::ConfirmFastStart, &[&configured_ms]))
+                        .style(theme::Text::Color(WARNING_COLOR)),
+                    button(lang.get(Label::StartAnyway)).on_press(Message::ConfirmStart),
+                    button(lang.get(Label::Cancel)).on_press(Message::CancelStart),
+                ]
+                .spacing(20),
+            )
+        } else {
+            None
+        };
+        let refresh_label = format_delay(self.tick_interval_ms() as u32);
+        let power_row = row![
+            checkbox(lang.get(Label::LowPower), self.low_power).on_toggle(Message::LowPowerToggled),
+            text(lang.fill(Label::RefreshEvery, &[&refresh_label])),
+        ]
+        .spacing(20);
+        let start_section = column![start_stop_row]
+            .push_maybe(confirm_row)
+            .push(power_row)
+            .spacing(10);
+
+        let measured_cps = self.measured_cps.load(Ordering::Relaxed) as f64 / 100.0;
+
+        let total_clicks = self.total_clicks.load(Ordering::Relaxed);
+        let active_secs = self.active_time_us.load(Ordering::Relaxed) as f64 / 1_000_000.0;
+        let average_cps = if active_secs > 0.0 {
+            total_clicks as f64 / active_secs
+        } else {
+            0.0
+        };
+        // Reset when a run starts fresh, and kept after it stops until the next one.
+        let session_clicks = self.clicks_done.load(Ordering::Relaxed);
+        let stats_row = row![
+            text(lang.fill(Label::SessionClicks, &[&session_clicks])),
+            text(lang.fill(
+                Label::TotalClicks,
+                &[&total_clicks, &format!("{:.1}", active_secs), &format!("{:.1}", average_cps)]
+            )),
+            button(lang.get(Label::Reset)).on_press(Message::ResetStats),
+        ]
+        .spacing(20);
+        let lifetime_label =
+            lang.fill(Label::LifetimeClicks, &[&self.lifetime_clicks.load(Ordering::Relaxed)]);
+
+        let click_indicator = text("●").style(theme::Text::Color(if self.click_flash {
+            CLICK_FLASH_COLOR
+        } else {
+            CLICK_IDLE_COLOR
+        }));
+        let click_error = self.click_error.lock().unwrap().clone();
+        let listener_error = self.listener_error.lock().unwrap().clone();
+        let test_click_status = self.test_click.lock().unwrap().clone().map(|status| match status {
+            TestClickStatus::Pending => {
+                let seconds = format!("{:.0}", TEST_CLICK_DELAY_MS as f64 / 1000.0);
+                text(lang.fill(Label::TestClickPending, &[&seconds]))
+            }
+            TestClickStatus::Sent(button, (x, y)) => {
+                text(lang.fill(Label::TestClickSent, &[&button, &x, &y]))
+            }
+            TestClickStatus::Failed(error) => {
+                text(error).style(theme::Text::Color(WARNING_COLOR))
+            }
+        });
+        // Same choice as the clicker thread makes, so this names what will stop it.
+        let now = Instant::now();
+        let stop_condition = StopCondition::first(
+            self.max_clicks.load(Ordering::Relaxed),
+            self.clicks_done.load(Ordering::Relaxed),
+            self.delay_ms.load(Ordering::Relaxed),
+            *self.run_deadline.lock().unwrap(),
+            *self.stop_at.lock().unwrap(),
+            now,
+        );
+        let until = |at: Instant| format_countdown(at.saturating_duration_since(now));
+        let stop_condition_label = match stop_condition {
+            _ if !self.is_clicking.is_running() => None,
+            StopCondition::None => None,
+            StopCondition::MaxClicks { remaining, eta } => {
+                Some(lang.fill(Label::AutoStopAfterClicks, &[&remaining, &until(eta)]))
+            }
+            StopCondition::MaxDuration(at) => Some(lang.fill(Label::AutoStopIn, &[&until(at)])),
+            StopCondition::AtTime(at) => Some(lang.fill(Label::AutoStopAtSchedule, &[&until(at)])),
+        };
+        let input_status = if input_error.is_some() {
+            lang.get(Label::BackendFailed)
+        } else {
+            lang.get(Label::BackendReady)
+        };
+        let listener_status = if listener_error.is_some() {
+            lang.get(Label::BackendFailed)
+        } else if self.listener_active.load(Ordering::Relaxed) {
+            lang.get(Label::ListenerActive)
+        } else {
+            lang.get(Label::ListenerWaiting)
+        };
+        let about_section = column![
+            text(lang.get(Label::About)),
+            text(lang.fill(Label::AboutVersion, &[&env!("CARGO_PKG_VERSION")])),
+            text(lang.fill(Label::AboutPlatform, &[&platform_description()])),
+            text(lang.fill(Label::AboutInput, &[&input_status])),
+            text(lang.fill(Label::AboutListener, &[&listener_status])),
+        ]
+        .spacing(5);
+
+        let status_section = column![row![click_indicator, text(label)].spacing(10)]
+            .push_maybe(stop_condition_label.map(text))
+            .push_maybe(test_click_status)
+            .push_maybe(
+                input_error.map(|error| text(error).style(theme::Text::Color(WARNING_COLOR))),
+            )
+            .push_maybe(
+                click_error.map(|error| text(error).style(theme::Text::Color(WARNING_COLOR))),
+            )
+            .push_maybe(
+                listener_error.map(|error| text(error).style(theme::Text::Color(WARNING_COLOR))),
+            )
+            .spacing(10);
+
+        // Far taller than any window, so everything below the fold has to be
+        // reachable by scrolling.
+        let content = column![
+            status_section,
+            text(lang.fill(Label::Measured, &[&format!("{:.1}", measured_cps)])),
+            stats_row,
+            text(lifetime_label),
+            speed_row,
+            text(interval_label),
+            speed_slider,
+            text(jitter_label),
+            slider(0..=MAX_JITTER_MS, jitter_value, Message::JitterChanged).step(5u32),
+            checkbox(lang.get(Label::Humanize), humanize_enabled)
+                .on_toggle(Message::HumanizeToggled),
+            text(lang.fill(Label::StdDev, &[&humanize_std_dev])),
+            slider(0..=MAX_HUMANIZE_STD_DEV_MS, humanize_std_dev, Message::HumanizeStdDevChanged)
+                .step(5u32),
+            checkbox(lang.get(Label::RandomRange), self.range_enabled.load(Ordering::Relaxed))
+                .on_toggle(Message::RangeToggled),
+            text(lang.fill(Label::Range, &[&range_min, &range_max])),
+            slider(MIN_DELAY_MS..=MAX_RANGE_DELAY_MS, range_min, Message::RangeMinChanged)
+                .step(10u32),
+            slider(MIN_DELAY_MS..=MAX_RANGE_DELAY_MS, range_max, Message::RangeMaxChanged)
+                .step(10u32),
+            text(hold_label),
+            slider(0..=MAX_HOLD_MS, hold_value, Message::HoldChanged).step(10u32),
+            text(lang.fill(Label::StartDelay, &[&start_delay_value])),
+            slider(0..=MAX_START_DELAY_MS, start_delay_value, Message::StartDelayChanged)
+                .step(100u32),
+            hotkey_section,
+            picklist_row,
+            modifier_row,
+            max_clicks_section,
+            max_run_row,
+            target_row,
+            overlay_checkbox,
+            pattern_row,
+            offset_row,
+            radius_row,
+            focus_checkbox,
+            target_window_row,
+            sequence_section,
+            path_row,
+            rhythm_row,
+            ramp_section,
+            burst_section,
+            key_press_row,
+            scroll_row,
+            drag_row,
+            jiggle_row,
+            start_section,
+            schedule_row,
+            profile_section,
+            about_section,
+        ]
+        .spacing(20)
+        .padding(20)
+        .width(Length::Shrink);
+        scrollable(content).height(Length::Fill).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digits_value_handles_empty_non_digit_and_overflowing_input() {
+        assert_eq!(digits_value(""), 0);
+        assert_eq!(digits_value("abc"), 0);
+        assert_eq!(digits_value("1a2b3"), 123);
+        assert_eq!(digits_value("4294967295"), u32::MAX);
+        assert_eq!(digits_value("99999999999999999999"), u32::MAX);
+    }
+
+    #[test]
+    fn huge_max_clicks_cap_the_stop_estimate_instead_of_overflowing() {
+        let now = Instant::now();
+        let condition = StopCondition::first(usize::MAX, 0, usize::MAX, None, None, now);
+        let StopCondition::MaxClicks { remaining, eta } = condition else {
+            panic!("expected a max clicks stop, got {:?}", condition);
+        };
+        assert_eq!(remaining, usize::MAX);
+        assert_eq!(eta, now + MAX_STOP_ETA);
+    }
+
+    #[test]
+    fn the_delay_runs_from_release_to_the_next_press() {
+        let delay_ms = 100;
+        for (hold_ms, click_count) in [(0, 1), (250, 1), (0, 2), (40, 3)] {
+            let press = press_duration_ms(hold_ms, click_count);
+            let period = Duration::from_millis(delay_ms + press);
+            let first_press = Instant::now();
+            let release = first_press + Duration::from_millis(press);
+            let next_press = next_click_due(Some(first_press), period, release);
+            assert_eq!(next_press - release, Duration::from_millis(delay_ms));
+        }
+        assert_eq!(press_duration_ms(50, 2), 50 * 2 + MULTI_CLICK_GAP_MS);
+        // Too far behind the grid starts over instead of catching up.
+        let start = Instant::now();
+        let period = Duration::from_millis(100);
+        let late = start + Duration::from_millis(500);
+        assert_eq!(next_click_due(Some(start), period, late), late + period);
+    }
+
+    #[test]
+    fn control_buttons_follow_the_clicker_state() {
+        use ClickerState::{Paused, Running, Stopped};
+        use Label::{Pause, Resume};
+        use Message::{PauseToggle, StartClicker, StopClicker};
+        // State, start blocked, then the expected Start, Pause/Resume and Stop.
+        let table = [
+            (Stopped, false, Some(StartClicker), (Pause, None), None),
+            (Stopped, true, None, (Pause, None), None),
+            (Running, false, None, (Pause, Some(PauseToggle)), Some(StopClicker)),
+            (Running, true, None, (Pause, Some(PauseToggle)), Some(StopClicker)),
+            (Paused, false, Some(StartClicker), (Resume, Some(PauseToggle)), Some(StopClicker)),
+            (Paused, true, None, (Resume, Some(PauseToggle)), Some(StopClicker)),
+        ];
+        // Message has no PartialEq, so compare what Debug prints.
+        let show = |message: &Option<Message>| format!("{:?}", message);
+        for (state, blocked, start, (pause_label, pause), stop) in table {
+            let [(start_label, got_start), (got_pause_label, got_pause), (stop_label, got_stop)] =
+                control_buttons(state, blocked);
+            let case = format!("{:?}, blocked {}", state, blocked);
+            assert_eq!(start_label, Label::Start, "{}", case);
+            assert_eq!(show(&got_start), show(&start), "{}", case);
+            assert_eq!(got_pause_label, pause_label, "{}", case);
+            assert_eq!(show(&got_pause), show(&pause), "{}", case);
+            assert_eq!(stop_label, Label::Stop, "{}", case);
+            assert_eq!(show(&got_stop), show(&stop), "{}", case);
+        }
+    }
+
+    #[test]
+    fn one_toggle_per_press_after_changing_the_hotkey() {
+        let mut hotkeys = HotkeyTracker::default();
+        let bindings =
+            |hotkey| HotkeyBindings { start: hotkey, stop: hotkey, mode: ClickMode::Toggle };
+        let f6 = HotkeyTrigger::Key(Key::F6);
+        let f8 = HotkeyTrigger::Key(Key::F8);
+        let mut now = Instant::now();
+        let mut press_and_release = |hotkeys: &mut HotkeyTracker, hotkey, trigger| {
+            now += Duration::from_millis(HOTKEY_DEBOUNCE_MS * 2);
+            let actions = [
+                hotkeys.action(bindings(hotkey), true, trigger, true, now),
+                // Auto-repeat while held.
+                hotkeys.action(bindings(hotkey), true, trigger, true, now),
+                hotkeys.action(bindings(hotkey), true, trigger, false, now),
+            ];
+            actions.into_iter().flatten().collect::<Vec<_>>()
+        };
+
+        assert_eq!(press_and_release(&mut hotkeys, Hotkey::F6, f6), [HotkeyAction::Toggle]);
+        // Changed twice, F6 -> F7 -> F8, with only the settings read per event.
+        assert!(press_and_release(&mut hotkeys, Hotkey::F7, f6).is_empty());
+        for _ in 0..3 {
+            assert_eq!(press_and_release(&mut hotkeys, Hotkey::F8, f8), [HotkeyAction::Toggle]);
+            assert!(press_and_release(&mut hotkeys, Hotkey::F8, f6).is_empty());
+        }
+    }
+
+    #[test]
+    fn hold_mode_starts_on_press_and_stops_on_release() {
+        let mut hotkeys = HotkeyTracker::default();
+        let bindings =
+            HotkeyBindings { start: Hotkey::F6, stop: Hotkey::F6, mode: ClickMode::Hold };
+        let f6 = HotkeyTrigger::Key(Key::F6);
+        let now = Instant::now();
+        assert_eq!(hotkeys.action(bindings, true, f6, true, now), Some(HotkeyAction::Start));
+        assert_eq!(hotkeys.action(bindings, true, f6, false, now), Some(HotkeyAction::Stop));
+        // A release without a press that was acted on does nothing.
+        assert_eq!(hotkeys.action(bindings, true, f6, false, now), None);
+        // Nor does a separate stop hotkey, which only applies to toggle mode.
+        let split = HotkeyBindings { stop: Hotkey::F7, ..bindings };
+        let f7 = HotkeyTrigger::Key(Key::F7);
+        assert_eq!(hotkeys.action(split, true, f7, true, now), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn backend_needs_a_display_on_linux() {
+        let result = create_backend(None, || -> u32 { panic!("must not be constructed") });
+        assert!(result.unwrap_err().contains("DISPLAY is not set"));
+    }
+
+    #[test]
+    fn backend_constructor_panic_becomes_an_error() {
+        let display = Some(std::ffi::OsString::from(":0"));
+        let result = create_backend(display, || -> u32 { panic!("no input backend") });
+        assert!(result.unwrap_err().contains("Failed to initialise"));
+    }
+
+    #[test]
+    fn backend_is_returned_when_construction_succeeds() {
+        let display = Some(std::ffi::OsString::from(":0"));
+        assert_eq!(create_backend(display, || 7), Ok(7));
+    }
+
+    #[test]
+    fn failsafe_corner_is_the_desktop_top_left() {
+        // Primary monitor only.
+        assert!(in_failsafe_corner(0.0, 2.0, (0, 0)));
+        assert!(!in_failsafe_corner(10.0, 0.0, (0, 0)));
+        // A second monitor to the left of and above the primary one.
+        assert!(in_failsafe_corner(-1920.0, -1080.0, (-1920, -1080)));
+        assert!(!in_failsafe_corner(0.0, 0.0, (-1920, -1080)));
     }
 }
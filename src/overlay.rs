@@ -0,0 +1,181 @@
+// ------------------- Target Overlay ---------------------
+
+// A small crosshair drawn above every other window at the click target. It is a
+// separate layered window with WS_EX_TRANSPARENT rather than part of the GUI, so
+// mouse input, including our own clicks, goes straight through it.
+#[cfg(target_os = "windows")]
+mod platform {
+    use std::sync::OnceLock;
+    use std::thread;
+
+    use windows_sys::Win32::Foundation::{HWND, LPARAM, LRESULT, RECT, WPARAM};
+    use windows_sys::Win32::Graphics::Gdi::{
+        BeginPaint, CreateSolidBrush, DeleteObject, EndPaint, FillRect, PAINTSTRUCT,
+    };
+    use windows_sys::Win32::System::LibraryLoader::GetModuleHandleW;
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        CreateWindowExW, DefWindowProcW, DispatchMessageW, GetMessageW, PostMessageW,
+        RegisterClassW, SetLayeredWindowAttributes, SetWindowPos, ShowWindow, TranslateMessage,
+        HWND_TOPMOST, LWA_COLORKEY, MSG, SWP_NOACTIVATE, SWP_SHOWWINDOW, SW_HIDE, WM_APP,
+        WM_PAINT, WNDCLASSW, WS_EX_LAYERED, WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW, WS_EX_TOPMOST,
+        WS_EX_TRANSPARENT, WS_POPUP,
+    };
+
+    // Odd, so the crosshair has a centre pixel.
+    const SIZE: i32 = 31;
+    const ARM_WIDTH: i32 = 3;
+    // Left undrawn in the middle so the target pixel itself stays visible.
+    const CENTRE_GAP: i32 = 5;
+    // COLORREFs are 0x00BBGGRR. Magenta is keyed out and becomes see-through.
+    const TRANSPARENT_COLOR: u32 = 0x00FF_00FF;
+    const CROSSHAIR_COLOR: u32 = 0x0000_00FF;
+
+    const WM_OVERLAY_MOVE: u32 = WM_APP + 1;
+    const WM_OVERLAY_HIDE: u32 = WM_APP + 2;
+
+    // 0 if the window couldn't be created; everything is then a no-op.
+    static WINDOW: OnceLock<HWND> = OnceLock::new();
+
+    fn wide(text: &str) -> Vec<u16> {
+        text.encode_utf16().chain(std::iter::once(0)).collect()
+    }
+
+    unsafe extern "system" fn window_proc(
+        hwnd: HWND,
+        message: u32,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    ) -> LRESULT {
+        // SAFETY: only called by Windows for our own window, with a valid `hwnd`;
+        // the paint calls stay between BeginPaint and EndPaint.
+        unsafe {
+            match message {
+                WM_OVERLAY_MOVE => {
+                    let (x, y) = (wparam as isize as i32, lparam as i32);
+                    let half = SIZE / 2;
+                    let flags = SWP_NOACTIVATE | SWP_SHOWWINDOW;
+                    SetWindowPos(hwnd, HWND_TOPMOST, x - half, y - half, SIZE, SIZE, flags);
+                    0
+                }
+                WM_OVERLAY_HIDE => {
+                    ShowWindow(hwnd, SW_HIDE);
+                    0
+                }
+                WM_PAINT => {
+                    let mut paint: PAINTSTRUCT = std::mem::zeroed();
+                    let hdc = BeginPaint(hwnd, &mut paint);
+                    let background = CreateSolidBrush(TRANSPARENT_COLOR);
+                    let crosshair = CreateSolidBrush(CROSSHAIR_COLOR);
+                    let whole = RECT { left: 0, top: 0, right: SIZE, bottom: SIZE };
+                    FillRect(hdc, &whole, background);
+                    let mid = SIZE / 2;
+                    // Each arm spans `near..far` across and stops short of the gap.
+                    let (near, far) = (mid - ARM_WIDTH / 2, mid + ARM_WIDTH / 2 + 1);
+                    let (gap_start, gap_end) = (mid - CENTRE_GAP / 2, mid + CENTRE_GAP / 2 + 1);
+                    let arms = [
+                        RECT { left: 0, top: near, right: gap_start, bottom: far },
+                        RECT { left: gap_end, top: near, right: SIZE, bottom: far },
+                        RECT { left: near, top: 0, right: far, bottom: gap_start },
+                        RECT { left: near, top: gap_end, right: far, bottom: SIZE },
+                    ];
+                    for rect in &arms {
+                        FillRect(hdc, rect, crosshair);
+                    }
+                    DeleteObject(background);
+                    DeleteObject(crosshair);
+                    EndPaint(hwnd, &paint);
+                    0
+                }
+                _ => DefWindowProcW(hwnd, message, wparam, lparam),
+            }
+        }
+    }
+
+    // Creates the window on its own thread the first time, since it needs a
+    // message loop that iced's event loop doesn't provide.
+    fn window() -> HWND {
+        *WINDOW.get_or_init(|| {
+            let (hwnd_tx, hwnd_rx) = std::sync::mpsc::channel();
+            thread::spawn(move || {
+                let class_name = wide("RustAutoClickerOverlay");
+                // SAFETY: the class name outlives both calls, the window procedure
+                // has the signature Win32 expects, and `message` is only read after
+                // GetMessageW has filled it in.
+                unsafe {
+                    let instance = GetModuleHandleW(std::ptr::null());
+                    let mut class: WNDCLASSW = std::mem::zeroed();
+                    class.lpfnWndProc = Some(window_proc);
+                    class.hInstance = instance;
+                    class.lpszClassName = class_name.as_ptr();
+                    RegisterClassW(&class);
+                    let hwnd = CreateWindowExW(
+                        WS_EX_LAYERED
+                            | WS_EX_TRANSPARENT
+                            | WS_EX_TOPMOST
+                            | WS_EX_TOOLWINDOW
+                            | WS_EX_NOACTIVATE,
+                        class_name.as_ptr(),
+                        class_name.as_ptr(),
+                        WS_POPUP,
+                        0,
+                        0,
+                        SIZE,
+                        SIZE,
+                        0,
+                        0,
+                        instance,
+                        std::ptr::null(),
+                    );
+                    let _ = hwnd_tx.send(hwnd);
+                    if hwnd == 0 {
+                        println!("Could not create the target overlay window.");
+                        return;
+                    }
+                    SetLayeredWindowAttributes(hwnd, TRANSPARENT_COLOR, 0, LWA_COLORKEY);
+                    let mut message: MSG = std::mem::zeroed();
+                    while GetMessageW(&mut message, 0, 0, 0) > 0 {
+                        TranslateMessage(&message);
+                        DispatchMessageW(&message);
+                    }
+                }
+            });
+            hwnd_rx.recv().unwrap_or(0)
+        })
+    }
+
+    pub fn show_at(x: i32, y: i32) {
+        let hwnd = window();
+        if hwnd != 0 {
+            // SAFETY: posting to a window we own; the procedure unpacks the same way.
+            unsafe {
+                PostMessageW(hwnd, WM_OVERLAY_MOVE, x as isize as WPARAM, y as LPARAM);
+            }
+        }
+    }
+
+    pub fn hide() {
+        // Nothing to hide if it was never shown.
+        if let Some(&hwnd) = WINDOW.get()
+            && hwnd != 0
+        {
+            // SAFETY: as in `show_at`.
+            unsafe {
+                PostMessageW(hwnd, WM_OVERLAY_HIDE, 0, 0);
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+pub use platform::{hide, show_at};
+
+// Only implemented for Windows so far. X11 and macOS need a compositor-aware
+// click-through window, which none of our dependencies offer.
+#[cfg(not(target_os = "windows"))]
+pub fn show_at(_x: i32, _y: i32) {}
+
+#[cfg(not(target_os = "windows"))]
+pub fn hide() {}
+
+pub const SUPPORTED: bool = cfg!(target_os = "windows");
+// --------------------------------------------------------
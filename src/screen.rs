@@ -0,0 +1,62 @@
+// ------------------- Screen Bounds ----------------------
+
+// Keep at least this much of a restored window on screen so it can be grabbed.
+const MIN_VISIBLE_PX: i32 = 100;
+
+// Left, top, right and bottom of the whole desktop across all monitors.
+#[cfg(target_os = "windows")]
+fn desktop_bounds() -> Option<(i32, i32, i32, i32)> {
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        GetSystemMetrics, SM_CXVIRTUALSCREEN, SM_CYVIRTUALSCREEN, SM_XVIRTUALSCREEN,
+        SM_YVIRTUALSCREEN,
+    };
+
+    // SAFETY: GetSystemMetrics only reads system settings.
+    let (left, top, width, height) = unsafe {
+        (
+            GetSystemMetrics(SM_XVIRTUALSCREEN),
+            GetSystemMetrics(SM_YVIRTUALSCREEN),
+            GetSystemMetrics(SM_CXVIRTUALSCREEN),
+            GetSystemMetrics(SM_CYVIRTUALSCREEN),
+        )
+    };
+    (width > 0 && height > 0).then_some((left, top, left + width, top + height))
+}
+
+// On X11 the main screen spans every monitor. macOS only reports the main
+// display, so a window saved on another one reopens at the default spot.
+// Asking means opening a new display connection, so it is only done once; a
+// monitor plugged in later is picked up on the next launch.
+#[cfg(not(target_os = "windows"))]
+fn desktop_bounds() -> Option<(i32, i32, i32, i32)> {
+    use enigo::MouseControllable;
+    use std::sync::OnceLock;
+
+    static BOUNDS: OnceLock<Option<(i32, i32, i32, i32)>> = OnceLock::new();
+    *BOUNDS.get_or_init(|| {
+        let enigo = crate::create_enigo().ok()?;
+        let (width, height) = enigo.main_display_size();
+        (width > 0 && height > 0).then_some((0, 0, width, height))
+    })
+}
+
+// Top-left corner of the whole desktop, negative with a monitor left of or above
+// the primary one. (0, 0) if the desktop can't be queried.
+pub fn desktop_origin() -> (i32, i32) {
+    desktop_bounds().map_or((0, 0), |(left, top, _, _)| (left, top))
+}
+
+// Whether a window whose top-left corner is at (x, y) would still be reachable.
+// If the desktop can't be queried the position is trusted as is.
+pub fn is_visible(x: i32, y: i32) -> bool {
+    match desktop_bounds() {
+        Some((left, top, right, bottom)) => {
+            x >= left - MIN_VISIBLE_PX
+                && x <= right - MIN_VISIBLE_PX
+                && y >= top
+                && y <= bottom - MIN_VISIBLE_PX
+        }
+        None => true,
+    }
+}
+// --------------------------------------------------------
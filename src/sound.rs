@@ -0,0 +1,65 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use rodio::source::{SineWave, Source};
+use rodio::{OutputStream, Sink};
+
+const TONE_DURATION_MS: u64 = 120;
+const TONE_VOLUME: f32 = 0.2;
+
+// Start/stop can be triggered from the GUI, the hotkey thread, the tray or the
+// clicker thread itself, so the switch lives here rather than being passed to
+// every caller.
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+// ------------------- Feedback Tones ---------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tone {
+    Start,
+    Stop,
+}
+
+impl Tone {
+    fn frequency(self) -> f32 {
+        match self {
+            Tone::Start => 880.0,
+            Tone::Stop => 440.0,
+        }
+    }
+}
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+// Plays on its own thread so a slow audio device can never hold up a click.
+pub fn play(tone: Tone) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    thread::spawn(move || {
+        let (_stream, handle) = match OutputStream::try_default() {
+            Ok(output) => output,
+            Err(e) => {
+                println!("No audio output for feedback sound: {}", e);
+                return;
+            }
+        };
+        let sink = match Sink::try_new(&handle) {
+            Ok(sink) => sink,
+            Err(e) => {
+                println!("Failed to play feedback sound: {}", e);
+                return;
+            }
+        };
+        sink.append(
+            SineWave::new(tone.frequency())
+                .take_duration(Duration::from_millis(TONE_DURATION_MS))
+                .amplify(TONE_VOLUME),
+        );
+        sink.sleep_until_end();
+    });
+}
+// --------------------------------------------------------
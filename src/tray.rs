@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use tray_icon::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
+
+use crate::ClickerFlag;
+
+const MENU_START_ID: &str = "start";
+const MENU_STOP_ID: &str = "stop";
+const MENU_QUIT_ID: &str = "quit";
+
+const ICON_SIZE: u32 = 16;
+const IDLE_ICON_RGB: [u8; 3] = [140, 140, 140];
+const RUNNING_ICON_RGB: [u8; 3] = [40, 200, 70];
+
+// ------------------- Tray Menu Actions ------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayAction {
+    Start,
+    Stop,
+    Quit,
+}
+
+// Menu clicks land on a global channel, so this works no matter which thread
+// owns the icon itself.
+pub fn poll_action() -> Option<TrayAction> {
+    while let Ok(event) = MenuEvent::receiver().try_recv() {
+        match event.id.0.as_str() {
+            MENU_START_ID => return Some(TrayAction::Start),
+            MENU_STOP_ID => return Some(TrayAction::Stop),
+            MENU_QUIT_ID => return Some(TrayAction::Quit),
+            _ => {}
+        }
+    }
+    None
+}
+// --------------------------------------------------------
+
+// ------------------- Tray Icon --------------------------
+
+// On Linux the icon has to live on a thread running the GTK main loop, so it is
+// spawned there and refreshes itself. Elsewhere it lives on the GUI thread and
+// is refreshed from the app's tick.
+pub struct Tray {
+    #[cfg(not(target_os = "linux"))]
+    icon: TrayIcon,
+    #[cfg(not(target_os = "linux"))]
+    shown_running: bool,
+}
+
+impl Tray {
+    #[cfg(not(target_os = "linux"))]
+    pub fn new(flag: Arc<ClickerFlag>) -> Option<Self> {
+        let running = flag.is_running();
+        build_icon(running).map(|icon| Self { icon, shown_running: running })
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn new(flag: Arc<ClickerFlag>) -> Option<Self> {
+        std::thread::spawn(move || {
+            if let Err(e) = gtk::init() {
+                println!("Failed to initialise GTK for the tray icon: {}", e);
+                return;
+            }
+            let mut running = flag.is_running();
+            let Some(icon) = build_icon(running) else {
+                return;
+            };
+            gtk::glib::timeout_add_local(
+                std::time::Duration::from_millis(crate::TICK_INTERVAL_MS),
+                move || {
+                    let now_running = flag.is_running();
+                    if now_running != running {
+                        running = now_running;
+                        show_state(&icon, running);
+                    }
+                    gtk::glib::ControlFlow::Continue
+                },
+            );
+            gtk::main();
+        });
+        Some(Self {})
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn update(&mut self, running: bool) {
+        if running != self.shown_running {
+            self.shown_running = running;
+            show_state(&self.icon, running);
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn update(&mut self, _running: bool) {}
+}
+
+fn build_icon(running: bool) -> Option<TrayIcon> {
+    let menu = Menu::new();
+    let start = MenuItem::with_id(MENU_START_ID, "Start", true, None);
+    let stop = MenuItem::with_id(MENU_STOP_ID, "Stop", true, None);
+    let quit = MenuItem::with_id(MENU_QUIT_ID, "Quit", true, None);
+    if let Err(e) = menu.append_items(&[&start, &stop, &PredefinedMenuItem::separator(), &quit]) {
+        println!("Failed to build tray menu: {}", e);
+        return None;
+    }
+
+    let builder = TrayIconBuilder::new()
+        .with_menu(Box::new(menu))
+        .with_tooltip(tooltip(running));
+    let builder = match state_icon(running) {
+        Some(icon) => builder.with_icon(icon),
+        None => builder,
+    };
+    match builder.build() {
+        Ok(icon) => {
+            println!("Tray icon created.");
+            Some(icon)
+        }
+        Err(e) => {
+            println!("Failed to create tray icon: {}", e);
+            None
+        }
+    }
+}
+
+fn show_state(icon: &TrayIcon, running: bool) {
+    if let Err(e) = icon.set_tooltip(Some(tooltip(running))) {
+        println!("Failed to update tray tooltip: {}", e);
+    }
+    if let Err(e) = icon.set_icon(state_icon(running)) {
+        println!("Failed to update tray icon: {}", e);
+    }
+}
+
+fn tooltip(running: bool) -> &'static str {
+    if running {
+        "Auto Clicker: running"
+    } else {
+        "Auto Clicker: stopped"
+    }
+}
+
+// A plain coloured square is enough to tell the two states apart and avoids
+// shipping image assets.
+fn state_icon(running: bool) -> Option<Icon> {
+    let [r, g, b] = if running { RUNNING_ICON_RGB } else { IDLE_ICON_RGB };
+    let rgba = [r, g, b, 255].repeat((ICON_SIZE * ICON_SIZE) as usize);
+    match Icon::from_rgba(rgba, ICON_SIZE, ICON_SIZE) {
+        Ok(icon) => Some(icon),
+        Err(e) => {
+            println!("Failed to create tray icon image: {}", e);
+            None
+        }
+    }
+}
+// --------------------------------------------------------